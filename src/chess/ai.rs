@@ -0,0 +1,127 @@
+use super::board::Board;
+use super::moves::Move;
+use super::piece::Color;
+
+/// Score of a checkmate, well above any material balance.
+const MATE_SCORE: i32 = 100_000;
+
+/// Picks the best move for the side to move with a fixed-depth minimax search.
+///
+/// Leaves are scored by material (see [`PieceType::value`](super::piece::PieceType::value)).
+/// Checkmate is scored as a large loss for the mated side, preferring faster mates, and
+/// stalemate is scored as a draw, so a winning side will not stalemate its opponent by accident.
+///
+/// Ties go to the first move in [`Board::get_all_legal_moves_sorted`] order, so the same
+/// position always yields the same move. Returns `None` when the side to move has no legal move.
+///
+/// # Examples
+/// ```
+/// use puzzle_engine::chess::{ai, Board};
+/// let board = Board::new();
+/// let mv = ai::best_move(&board, 1).unwrap();
+/// assert!(board.get_all_legal_moves().contains(&mv));
+/// ```
+#[must_use]
+pub fn best_move(board: &Board, depth: u32) -> Option<Move> {
+    let mut best: Option<(i32, Move)> = None;
+    for mv in board.get_all_legal_moves_sorted(board.turn) {
+        let mut next = board.clone();
+        if next.try_move(mv.from, mv.to, mv.promotion).is_err() {
+            continue;
+        }
+        let score = -negamax(&next, depth.saturating_sub(1));
+        if best.is_none_or(|(best_score, _)| score > best_score) {
+            best = Some((score, mv));
+        }
+    }
+    best.map(|(_, mv)| mv)
+}
+
+/// Scores `board` from the point of view of the side to move.
+fn negamax(board: &Board, depth: u32) -> i32 {
+    let color = board.turn;
+    if board.is_checkmate(color) {
+        // A mate found with more depth remaining is closer, so it scores higher for the winner
+        return -MATE_SCORE - i32::try_from(depth).unwrap_or(i32::MAX - MATE_SCORE);
+    }
+    if board.is_stalemate(color) || board.is_draw() {
+        return 0;
+    }
+    if depth == 0 {
+        return material(board, color);
+    }
+    board
+        .get_all_legal_moves_sorted(color)
+        .into_iter()
+        .filter_map(|mv| {
+            let mut next = board.clone();
+            next.try_move(mv.from, mv.to, mv.promotion).ok()?;
+            Some(-negamax(&next, depth - 1))
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Material balance for `color`.
+fn material(board: &Board, color: Color) -> i32 {
+    board
+        .squares()
+        .values()
+        .map(|piece| {
+            let value = i32::try_from(piece.kind.value()).unwrap_or(0);
+            if piece.color == color { value } else { -value }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::{GameState, PieceType, Position};
+
+    fn king_and_queen_vs_king() -> Board {
+        let mut board = Board::new();
+        board.initialize_custom(
+            vec![
+                ('h', 8, Color::Black, PieceType::King),
+                ('g', 6, Color::White, PieceType::King),
+                ('f', 1, Color::White, PieceType::Queen),
+            ],
+            Color::White,
+            GameState::Ongoing,
+        );
+        board
+    }
+
+    #[test]
+    fn test_prefers_mate_over_stalemate() {
+        let board = king_and_queen_vs_king();
+
+        // Qf7 would leave Black with no legal move while not in check
+        let mut stalemated = board.clone();
+        let f1 = Position::new('f', 1).unwrap();
+        stalemated.try_move(f1, Position::new('f', 7).unwrap(), None).unwrap();
+        assert!(stalemated.is_stalemate(Color::Black));
+
+        for depth in [1, 2] {
+            let mv = best_move(&board, depth).unwrap();
+            let mut after = board.clone();
+            after.try_move(mv.from, mv.to, mv.promotion).unwrap();
+            assert!(after.is_checkmate(Color::Black), "Depth {depth} played {mv:?} instead of mating.");
+        }
+    }
+
+    #[test]
+    fn test_ties_are_broken_deterministically() {
+        for _ in 0..5 {
+            assert_eq!(best_move(&Board::new(), 1), Some(Move::from_uci("a2a3").unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_no_move_when_mated() {
+        let mut board = king_and_queen_vs_king();
+        board.try_move(Position::new('f', 1).unwrap(), Position::new('f', 8).unwrap(), None).unwrap();
+        assert_eq!(best_move(&board, 2), None);
+    }
+}
@@ -1,5 +1,10 @@
+use super::moves::{Move, MoveKind};
 use super::piece::{Piece, Color, PieceType};
 use super::position::Position;
+#[cfg(test)]
+use super::position::sq;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::collections::HashMap;
 use std::fmt::Write as FmtWrite;
 
@@ -7,7 +12,9 @@ use std::fmt::Write as FmtWrite;
 #[derive(Clone, Debug)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Board {
-    pub squares: HashMap<Position, Piece>,
+    // Pieces by square. Private so that every edit goes through `put` and `take`, which keep
+    // the caches below in sync.
+    squares: HashMap<Position, Piece>,
     pub turn: Color,
     pub game_state: GameState,
 
@@ -16,7 +23,27 @@ pub struct Board {
     pub white_can_castle_queenside: bool,
     pub black_can_castle_kingside: bool,
     pub black_can_castle_queenside: bool,
+    /// Starting file of both kings; `'e'` in standard chess, anywhere between the rooks in Chess960.
+    pub king_start_file: char,
+    /// Starting file of the rooks that castle kingside.
+    pub kingside_rook_file: char,
+    /// Starting file of the rooks that castle queenside.
+    pub queenside_rook_file: char,
     pub en_passant_target: Option<Position>,
+
+    /// Number of half-moves since the last capture or pawn move (fifty-move rule).
+    pub halfmove_clock: u32,
+    /// Number of the current full move, starting at 1 and incremented after each Black move.
+    pub fullmove_number: u32,
+    /// Whether repetition and fifty-move draws end the game automatically or must be claimed.
+    pub draw_policy: DrawPolicy,
+    // Position keys of every position reached, used for repetition detection.
+    position_history: Vec<String>,
+    // One bit per occupied square for each color (white, black), mirroring `squares`.
+    occupancy: [u64; 2],
+    // Where each king stands, if it is on the board.
+    white_king: Option<Position>,
+    black_king: Option<Position>,
 }
 
 /// Represents the current state of a chess game.
@@ -25,7 +52,83 @@ pub enum GameState {
     Ongoing,
     Checkmate(Color), // The player who is checkmated
     Stalemate,
-    Draw, // Optional: add later (repetition, 50-move rule, etc.)
+    Draw(DrawReason), // The reason the game was drawn
+}
+
+/// The final result of a finished game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    WhiteWins,
+    BlackWins,
+    Draw,
+}
+
+/// Why a game ended in a draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawReason {
+    Stalemate,
+    ThreefoldRepetition,
+    FiftyMove,
+    InsufficientMaterial,
+    Agreement,
+}
+
+/// How threefold repetition and the fifty-move rule end a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DrawPolicy {
+    /// The game is drawn as soon as either condition arises.
+    #[default]
+    Automatic,
+    /// The game continues until a player calls [`Board::claim_draw`].
+    Claimable,
+}
+
+/// Counts of pawn weaknesses and strengths for one side, as returned by [`Board::pawn_structure`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PawnStructure {
+    /// Extra pawns on files holding more than one friendly pawn; two pawns on a file count once.
+    pub doubled: usize,
+    /// Pawns with no friendly pawn on either adjacent file.
+    pub isolated: usize,
+    /// Pawns with no enemy pawn ahead of them on their own or an adjacent file.
+    pub passed: usize,
+}
+
+/// Controls how [`Board::write_display_with`] draws the board.
+///
+/// The default matches [`Board::display`]: Unicode pieces, `.` for every empty square and
+/// White at the bottom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayOptions {
+    /// Glyph for empty light squares.
+    pub light_square: char,
+    /// Glyph for empty dark squares.
+    pub dark_square: char,
+    /// Draw pieces as Unicode symbols; otherwise as letters, uppercase for White.
+    pub unicode: bool,
+    /// The side drawn at the bottom of the board.
+    pub orientation: Color,
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        Self { light_square: '.', dark_square: '.', unicode: true, orientation: Color::White }
+    }
+}
+
+/// Everything needed to take back a move played with [`Board::make`].
+#[derive(Clone, Copy, Debug)]
+struct Undo {
+    from: Position,
+    to: Position,
+    moved: Piece,
+    // The captured piece and the square it stood on (differs from `to` for en passant).
+    captured: Option<(Position, Piece)>,
+    // For castling: where the king landed, and the rook's start and end squares.
+    castle: Option<(Position, Position, Position)>,
+    castling_rights: [bool; 4],
+    en_passant_target: Option<Position>,
+    turn: Color,
 }
 
 
@@ -40,15 +143,247 @@ impl Board {
             white_can_castle_queenside: true,
             black_can_castle_kingside: true,
             black_can_castle_queenside: true,
+            king_start_file: 'e',
+            kingside_rook_file: 'h',
+            queenside_rook_file: 'a',
             en_passant_target: None,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            draw_policy: DrawPolicy::Automatic,
+            position_history: Vec::new(),
+            occupancy: [0; 2],
+            white_king: None,
+            black_king: None,
         };
         board.reset();
         board
     }
 
+    /// Creates a board with no pieces, White to move and no castling rights.
+    ///
+    /// Useful for setting up puzzles piece by piece with [`Board::set_piece`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::empty();
+    /// assert!(board.squares().is_empty());
+    /// assert!(!board.white_can_castle_kingside);
+    /// board.set_piece(Position::new('e', 1).unwrap(), Piece { color: Color::White, kind: PieceType::King });
+    /// assert_eq!(board.find_king(Color::White), Position::new('e', 1));
+    /// ```
+    #[must_use]
+    pub fn empty() -> Self {
+        let mut board = Board::new();
+        board.clear();
+        board
+    }
+
+    /// Removes every piece from the board.
+    ///
+    /// Castling rights and the en passant target are cleared and the game is marked ongoing;
+    /// the side to move is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// board.clear();
+    /// assert!(board.squares().is_empty());
+    /// ```
+    pub fn clear(&mut self) {
+        self.squares.clear();
+        self.white_can_castle_kingside = false;
+        self.white_can_castle_queenside = false;
+        self.black_can_castle_kingside = false;
+        self.black_can_castle_queenside = false;
+        self.en_passant_target = None;
+        self.game_state = GameState::Ongoing;
+        self.halfmove_clock = 0;
+        self.fullmove_number = 1;
+        self.sync_caches();
+        self.position_history = vec![self.position_key()];
+    }
+
+    /// Returns the pieces on the board, keyed by square.
+    ///
+    /// Use [`Board::set_piece`] and [`Board::remove_piece`] to edit the position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let board = Board::new();
+    /// assert_eq!(board.squares().len(), 32);
+    /// ```
+    #[must_use]
+    pub fn squares(&self) -> &HashMap<Position, Piece> {
+        &self.squares
+    }
+
+    /// Places a piece on a square, replacing whatever stood there.
+    ///
+    /// This revokes any castling right that depended on a replaced king or rook.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::empty();
+    /// board.set_piece(Position::new('d', 4).unwrap(), Piece { color: Color::White, kind: PieceType::Queen });
+    /// assert_eq!(board.squares().len(), 1);
+    /// ```
+    pub fn set_piece(&mut self, pos: Position, piece: Piece) {
+        self.remove_piece(pos);
+        self.put(pos, piece);
+    }
+
+    /// Removes and returns the piece on a square, if any.
+    ///
+    /// Removing a king clears both of its side's castling rights, and removing a rook from its
+    /// starting square clears the right to castle on that side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// board.remove_piece(Position::new('h', 1).unwrap());
+    /// assert!(!board.white_can_castle_kingside);
+    /// assert!(board.white_can_castle_queenside);
+    /// ```
+    pub fn remove_piece(&mut self, pos: Position) -> Option<Piece> {
+        let piece = self.take(pos)?;
+        self.revoke_castling_rights(piece, pos);
+        Some(piece)
+    }
+
+    /// Creates a Chess960 (Fischer Random) board from a seed.
+    ///
+    /// The back rank is shuffled so that the bishops stand on opposite colors and the king
+    /// stands between the two rooks; Black's pieces mirror White's. All castling rights are
+    /// granted, using the randomized rook files. The same seed always yields the same position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let board = Board::new_chess960(518);
+    /// assert_eq!(board.squares().len(), 32);
+    /// assert!(board.kingside_rook_file > board.king_start_file);
+    /// assert!(board.king_start_file > board.queenside_rook_file);
+    /// ```
+    #[must_use]
+    pub fn new_chess960(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut back_rank: [Option<PieceType>; 8] = [None; 8];
+
+        // Bishops on one dark (even index) and one light (odd index) square
+        back_rank[rng.random_range(0..4) * 2] = Some(PieceType::Bishop);
+        back_rank[rng.random_range(0..4) * 2 + 1] = Some(PieceType::Bishop);
+        for kind in [PieceType::Queen, PieceType::Knight, PieceType::Knight] {
+            let free: Vec<usize> = (0..8).filter(|&i| back_rank[i].is_none()).collect();
+            back_rank[free[rng.random_range(0..free.len())]] = Some(kind);
+        }
+        // The last three squares hold rook, king, rook in that order
+        let free: Vec<usize> = (0..8).filter(|&i| back_rank[i].is_none()).collect();
+        for (i, kind) in free.iter().zip([PieceType::Rook, PieceType::King, PieceType::Rook]) {
+            back_rank[*i] = Some(kind);
+        }
+
+        let mut board = Board::new();
+        board.squares.clear();
+        for (file, kind) in ('a'..='h').zip(back_rank.into_iter().flatten()) {
+            board.squares.insert(Position { file, rank: 1 }, Piece { color: Color::White, kind });
+            board.squares.insert(Position { file, rank: 8 }, Piece { color: Color::Black, kind });
+            board.squares.insert(Position { file, rank: 2 }, Piece { color: Color::White, kind: PieceType::Pawn });
+            board.squares.insert(Position { file, rank: 7 }, Piece { color: Color::Black, kind: PieceType::Pawn });
+        }
+        let files = ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h'];
+        board.queenside_rook_file = files[free[0]];
+        board.king_start_file = files[free[1]];
+        board.kingside_rook_file = files[free[2]];
+        board.sync_caches();
+        board.position_history = vec![board.position_key()];
+        board
+    }
+
+    /// Replays a sequence of moves from the standard starting position.
+    ///
+    /// Each move is applied with [`Board::try_move`], so every rule enforced there applies.
+    ///
+    /// # Arguments
+    ///
+    /// - `moves` — The moves to play, in order, starting with White.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the zero-based ply index of the first illegal move and why it was rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let moves = [
+    ///     Move::new(Position::new('e', 2).unwrap(), Position::new('e', 4).unwrap(), None),
+    ///     Move::new(Position::new('e', 7).unwrap(), Position::new('e', 5).unwrap(), None),
+    /// ];
+    /// let board = Board::from_moves(&moves).unwrap();
+    /// assert_eq!(board.turn, Color::White);
+    /// ```
+    pub fn from_moves(moves: &[Move]) -> Result<Board, String> {
+        let mut board = Board::new();
+        for (ply, mv) in moves.iter().enumerate() {
+            board
+                .try_move(mv.from, mv.to, mv.promotion)
+                .map_err(|err| format!("Illegal move {mv} at ply {ply}: {err}"))?;
+        }
+        Ok(board)
+    }
+
+    /// Plays a sequence of moves, all or nothing.
+    ///
+    /// Each move is applied with [`Board::try_move`]. If any is rejected, the board is
+    /// restored to exactly the state it had before the call.
+    ///
+    /// # Errors
+    ///
+    /// Returns the zero-based index of the first rejected move and the reason it was rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// let moves = [Move::from_uci("e2e4").unwrap(), Move::from_uci("e2e4").unwrap()];
+    /// assert_eq!(board.apply_moves(&moves).unwrap_err().0, 1);
+    /// assert_eq!(board.turn, Color::White);
+    /// ```
+    pub fn apply_moves(&mut self, moves: &[Move]) -> Result<(), (usize, String)> {
+        let before = self.clone();
+        for (index, mv) in moves.iter().enumerate() {
+            if let Err(err) = self.try_move(mv.from, mv.to, mv.promotion) {
+                *self = before;
+                return Err((index, err));
+            }
+        }
+        Ok(())
+    }
+
     /// Initializes the board with a custom set of pieces, turn, and game state.
     ///
     /// This method clears any existing pieces and replaces them with the provided ones.
+    /// All castling rights are removed; grant them with [`Board::set_castling_rights`].
     ///
     /// # Arguments
     ///
@@ -78,10 +413,10 @@ impl Board {
     ///     GameState::Ongoing,
     /// );
     ///
-    /// assert_eq!(board.squares.len(), 3);
+    /// assert_eq!(board.squares().len(), 3);
     /// assert_eq!(board.turn, Color::Black);
     /// assert_eq!(board.game_state, GameState::Ongoing);
-    /// assert!(board.squares.contains_key(&Position::new('e', 1).unwrap()));
+    /// assert!(board.squares().contains_key(&Position::new('e', 1).unwrap()));
     /// ```
     ///
     /// # Notes
@@ -105,6 +440,108 @@ impl Board {
         }
         self.turn = turn;
         self.game_state = game_state;
+        self.white_can_castle_kingside = false;
+        self.white_can_castle_queenside = false;
+        self.black_can_castle_kingside = false;
+        self.black_can_castle_queenside = false;
+        self.king_start_file = 'e';
+        self.kingside_rook_file = 'h';
+        self.queenside_rook_file = 'a';
+        self.halfmove_clock = 0;
+        self.fullmove_number = 1;
+        self.sync_caches();
+        self.position_history = vec![self.position_key()];
+    }
+
+    /// Sets which castling moves remain available: white kingside, white queenside, black
+    /// kingside and black queenside.
+    ///
+    /// A right only allows castling while the king and rook still stand on their starting squares.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// board.initialize_custom(
+    ///     vec![
+    ///         ('e', 1, Color::White, PieceType::King),
+    ///         ('h', 1, Color::White, PieceType::Rook),
+    ///         ('e', 8, Color::Black, PieceType::King),
+    ///     ],
+    ///     Color::White,
+    ///     GameState::Ongoing,
+    /// );
+    /// let (e1, g1) = (Position::new('e', 1).unwrap(), Position::new('g', 1).unwrap());
+    /// assert!(!board.is_legal_move(e1, g1, None));
+    /// board.set_castling_rights(true, false, false, false);
+    /// assert!(board.is_legal_move(e1, g1, None));
+    /// ```
+    #[allow(clippy::fn_params_excessive_bools)]
+    pub fn set_castling_rights(&mut self, wk: bool, wq: bool, bk: bool, bq: bool) {
+        self.white_can_castle_kingside = wk;
+        self.white_can_castle_queenside = wq;
+        self.black_can_castle_kingside = bk;
+        self.black_can_castle_queenside = bq;
+        // The current position's repetition key includes the castling rights
+        let key = self.position_key();
+        if let Some(last) = self.position_history.last_mut() {
+            *last = key;
+        }
+    }
+
+    /// Checks that the board holds a legal chess position, as needed after editing it by hand.
+    ///
+    /// Each side must have exactly one king, no pawn may stand on the first or last rank, the
+    /// side that just moved must not be left in check, and every castling right needs its king
+    /// and rook on their starting squares.
+    ///
+    /// # Errors
+    ///
+    /// Returns every problem found, one message each.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// assert!(Board::new().validate_position().is_ok());
+    /// let problems = Board::empty().validate_position().unwrap_err();
+    /// assert_eq!(problems.len(), 2);
+    /// ```
+    pub fn validate_position(&self) -> Result<(), Vec<String>> {
+        let mut problems = Vec::new();
+        for color in [Color::White, Color::Black] {
+            let kings = self.piece_counts(color)[&PieceType::King];
+            if kings != 1 {
+                problems.push(format!("{color:?} has {kings} kings; expected exactly 1."));
+            }
+        }
+        for pos in Position::all().filter(|pos| pos.rank == 1 || pos.rank == 8) {
+            if self.squares.get(&pos).is_some_and(|piece| piece.kind == PieceType::Pawn) {
+                problems.push(format!("Pawn on {pos} cannot stand on the first or last rank."));
+            }
+        }
+        let waiting = self.turn.opponent();
+        if self.piece_counts(waiting)[&PieceType::King] == 1 && self.is_in_check(waiting) {
+            problems.push(format!("{waiting:?} is in check but it is {:?}'s turn.", self.turn));
+        }
+        let rights = [
+            (Color::White, true, self.white_can_castle_kingside),
+            (Color::White, false, self.white_can_castle_queenside),
+            (Color::Black, true, self.black_can_castle_kingside),
+            (Color::Black, false, self.black_can_castle_queenside),
+        ];
+        for (color, kingside, allowed) in rights {
+            let (king_from, rook_from, _, _) = self.castling_squares(color, kingside);
+            let in_place = |pos: Position, kind: PieceType| self.squares.get(&pos) == Some(&Piece { color, kind });
+            if allowed && !(in_place(king_from, PieceType::King) && in_place(rook_from, PieceType::Rook)) {
+                let side = if kingside { "kingside" } else { "queenside" };
+                problems.push(format!("{color:?} may castle {side}, but its king or rook is not on its starting square."));
+            }
+        }
+        if problems.is_empty() { Ok(()) } else { Err(problems) }
     }
 
     /// Resets the chess board to the standard initial setup.
@@ -135,7 +572,7 @@ impl Board {
     /// board.reset();
     ///
     /// // After reset, the board should have a white pawn back on e2
-    /// let e2_piece = board.squares.get(&Position::new('e', 2).unwrap()).unwrap();
+    /// let e2_piece = board.squares().get(&Position::new('e', 2).unwrap()).unwrap();
     /// assert_eq!(e2_piece.color, Color::White);
     /// assert_eq!(e2_piece.kind, PieceType::Pawn);
     ///
@@ -190,7 +627,16 @@ impl Board {
         self.white_can_castle_queenside = true;
         self.black_can_castle_kingside = true;
         self.black_can_castle_queenside = true;
+        self.king_start_file = 'e';
+        self.kingside_rook_file = 'h';
+        self.queenside_rook_file = 'a';
         self.en_passant_target = None;
+
+        self.game_state = GameState::Ongoing;
+        self.halfmove_clock = 0;
+        self.fullmove_number = 1;
+        self.sync_caches();
+        self.position_history = vec![self.position_key()];
     }
 
     /// Attempts to move a piece from one position to another according to chess rules.
@@ -220,6 +666,7 @@ impl Board {
     ///
     /// # Errors
     ///
+    /// - `"Game is over"` if the game has already ended in checkmate, stalemate or a draw.
     /// - `"No piece at starting position."` if no piece is found at `from`.
     /// - `"Not your turn."` if a player attempts to move a piece belonging to the opponent.
     /// - `"Illegal move."` if the desired move is not legal for the selected piece.
@@ -250,6 +697,10 @@ impl Board {
     /// - The method assumes that [`Position::new`] has already validated that the provided positions are on the board.
     ///
     pub fn try_move(&mut self, from: Position, to: Position, promotion: Option<PieceType>) -> Result<(), String> {
+        if self.game_state != GameState::Ongoing {
+            return Err("Game is over".to_string());
+        }
+
         let piece = match self.squares.get(&from).copied() {
             Some(p) => p,
             None => return Err("No piece at starting position.".to_string()),
@@ -264,49 +715,43 @@ impl Board {
             return Err("Illegal move.".to_string());
         }
     
-        // Special handling: castling
-        if piece.kind == PieceType::King {
-            let castle_rank = match piece.color {
-                Color::White => 1,
-                Color::Black => 8,
+        // Validate the promotion piece before touching the board
+        let promotes = piece.kind == PieceType::Pawn
+            && match piece.color {
+                Color::White => to.rank == 8,
+                Color::Black => to.rank == 1,
             };
-            if from.rank == castle_rank && from.file == 'e' {
-                if to.file == 'g' && to.rank == castle_rank {
-                    // Kingside castling
-                    return self.try_castle(piece.color, true);
-                } else if to.file == 'c' && to.rank == castle_rank {
-                    // Queenside castling
-                    return self.try_castle(piece.color, false);
+        if promotes {
+            if let Some(kind) = promotion {
+                if !PieceType::promotion_choices().contains(&kind) {
+                    return Err("Invalid promotion piece.".to_string());
                 }
             }
         }
-        // Clone board and simulate move to check for illegal moves
-        let mut clone = self.clone();
-        clone.force_move(from, to)?;
-    
-        // If after the move our king is in check, reject
-        if clone.is_in_check(piece.color) {
+
+        // Special handling: castling
+        if piece.kind == PieceType::King {
+            if let Some(kingside) = self.castling_side(piece.color, from, to) {
+                self.try_castle(piece.color, kingside)?;
+                self.en_passant_target = None;
+                self.finish_move(false);
+                return Ok(());
+            }
+        }
+        // Simulate the move and reject it if our king is left in check
+        if !self.is_move_safe(from, to) {
             return Err("Move would leave king in check.".to_string());
         }
     
+        let is_capture = self.squares.contains_key(&to)
+            || (piece.kind == PieceType::Pawn && self.en_passant_target == Some(to));
+
         // Move is valid; perform it
         self.force_move(from, to)?;
-        // Check if promotion is needed
-        if let Some(moved_piece) = self.squares.get_mut(&to) {
-            if moved_piece.kind == PieceType::Pawn {
-                let promote = match moved_piece.color {
-                    Color::White if to.rank == 8 => true,
-                    Color::Black if to.rank == 1 => true,
-                    _ => false,
-                };
-                if promote {
-                    let new_piece = match promotion {
-                        Some(PieceType::Queen | PieceType::Rook | PieceType::Bishop | PieceType::Knight) => promotion.unwrap(),
-                        None => PieceType::Queen, // Default to queen if not specified
-                        _ => return Err("Invalid promotion piece.".to_string()),
-                    };
-                    moved_piece.kind = new_piece;
-                }
+        // Promote, defaulting to a queen if no piece was specified
+        if promotes {
+            if let Some(moved_piece) = self.squares.get_mut(&to) {
+                moved_piece.kind = promotion.unwrap_or(PieceType::Queen);
             }
         }
         // EN Passant Hnadling
@@ -315,84 +760,488 @@ impl Board {
         if piece.kind == PieceType::Pawn {
             let diff = (to.rank as i8 - from.rank as i8).abs();
             if diff == 2 {
-                // Set en passant square behind pawn
-                let file = from.file;
-                let target_rank = from.rank - 1;
-                self.en_passant_target = Some(Position::new(file, target_rank).unwrap());
+                // Set en passant square behind pawn, between its start and end squares
+                self.en_passant_target = Some(Position { file: from.file, rank: u8::midpoint(from.rank, to.rank) });
             }
         }
         else {
             self.en_passant_target = None;
         }
+        self.finish_move(piece.kind == PieceType::Pawn || is_capture);
+
+        Ok(())
+    }
+
+    /// Checks whether [`Board::try_move`] would accept the move, without changing the board.
+    ///
+    /// The same checks are applied: the game must be ongoing, the piece must belong to the side
+    /// to move, the destination must be reachable, the promotion piece must be valid, and the
+    /// move must not leave the mover's king in check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let board = Board::new();
+    /// assert!(board.is_legal_move(Position::new('e', 2).unwrap(), Position::new('e', 4).unwrap(), None));
+    /// assert!(!board.is_legal_move(Position::new('e', 7).unwrap(), Position::new('e', 5).unwrap(), None));
+    /// ```
+    #[must_use]
+    pub fn is_legal_move(&self, from: Position, to: Position, promotion: Option<PieceType>) -> bool {
+        if self.game_state != GameState::Ongoing {
+            return false;
+        }
+        let Some(piece) = self.squares.get(&from) else {
+            return false;
+        };
+        if piece.color != self.turn || !self.get_legal_moves(from).contains(&to) {
+            return false;
+        }
+        let promotes = piece.kind == PieceType::Pawn && (to.rank == 1 || to.rank == 8);
+        if promotes && promotion.is_some_and(|kind| !PieceType::promotion_choices().contains(&kind)) {
+            return false;
+        }
+        self.clone().is_move_safe(from, to)
+    }
+
+    /// Bookkeeping shared by every completed move: the fifty-move clock, turn switch,
+    /// repetition history, and checkmate/stalemate/draw detection for the side now to move.
+    fn finish_move(&mut self, resets_halfmove_clock: bool) {
+        // Fifty-move rule bookkeeping
+        if resets_halfmove_clock {
+            self.halfmove_clock = 0;
+        } else {
+            self.halfmove_clock += 1;
+        }
+        if self.turn == Color::Black {
+            self.fullmove_number += 1;
+        }
         // Switch turn
-        self.turn = Self::opponent_color(self.turn);
+        self.turn = self.turn.opponent();
+        self.position_history.push(self.position_key());
 
-        // After move, check if opponent is checkmated or stalemated
+        // After move, check if opponent is checkmated or the game is drawn
         if self.is_checkmate(self.turn) {
             self.game_state = GameState::Checkmate(self.turn);
         } else if self.is_stalemate(self.turn) {
-            self.game_state = GameState::Draw;
+            self.game_state = GameState::Draw(DrawReason::Stalemate);
+        } else if self.is_insufficient_material() {
+            self.game_state = GameState::Draw(DrawReason::InsufficientMaterial);
+        } else if let (DrawPolicy::Automatic, Some(reason)) = (self.draw_policy, self.claimable_draw()) {
+            self.game_state = GameState::Draw(reason);
         } else {
             self.game_state = GameState::Ongoing;
         }
+    }
 
-        Ok(())
+    /// Summarizes the current [`GameState`] as a single [`Outcome`].
+    ///
+    /// Returns `None` while the game is still ongoing. A checkmate is reported as a win
+    /// for the opponent of the mated color; stalemates and all draws report [`Outcome::Draw`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// assert_eq!(board.game_result(), None);
+    /// board.game_state = GameState::Checkmate(Color::Black);
+    /// assert_eq!(board.game_result(), Some(Outcome::WhiteWins));
+    /// ```
+    #[must_use]
+    pub fn game_result(&self) -> Option<Outcome> {
+        match self.game_state {
+            GameState::Ongoing => None,
+            GameState::Checkmate(Color::White) => Some(Outcome::BlackWins),
+            GameState::Checkmate(Color::Black) => Some(Outcome::WhiteWins),
+            GameState::Stalemate | GameState::Draw(_) => Some(Outcome::Draw),
+        }
     }
 
-    /// Trys to castle
-    fn try_castle(&mut self, color: Color, kingside: bool) -> Result<(), String> {
-        let (rank, rook_file, king_from, king_to, rook_to) = match (color, kingside) {
-            (Color::White, true) => (1, 'h', Position::new('e', 1).unwrap(), Position::new('g', 1).unwrap(), Position::new('f', 1).unwrap()),
-            (Color::White, false) => (1, 'a', Position::new('e', 1).unwrap(), Position::new('c', 1).unwrap(), Position::new('d', 1).unwrap()),
-            (Color::Black, true) => (8, 'h', Position::new('e', 8).unwrap(), Position::new('g', 8).unwrap(), Position::new('f', 8).unwrap()),
-            (Color::Black, false) => (8, 'a', Position::new('e', 8).unwrap(), Position::new('c', 8).unwrap(), Position::new('d', 8).unwrap()),
-        };
-    
-        // 1. Check permission
-        let can_castle = match (color, kingside) {
-            (Color::White, true) => self.white_can_castle_kingside,
-            (Color::White, false) => self.white_can_castle_queenside,
-            (Color::Black, true) => self.black_can_castle_kingside,
-            (Color::Black, false) => self.black_can_castle_queenside,
-        };
-        if !can_castle {
-            return Err("Castling not allowed (king or rook has moved)".to_string());
+    /// Returns `true` once the game has ended by checkmate, stalemate or any draw.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// assert!(!board.is_game_over());
+    /// board.agree_draw();
+    /// assert!(board.is_game_over());
+    /// ```
+    #[must_use]
+    pub fn is_game_over(&self) -> bool {
+        self.game_state != GameState::Ongoing
+    }
+
+    /// Returns the side that delivered checkmate, or `None` if nobody has won.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// board.game_state = GameState::Checkmate(Color::White);
+    /// assert_eq!(board.winner(), Some(Color::Black));
+    /// ```
+    #[must_use]
+    pub fn winner(&self) -> Option<Color> {
+        match self.game_state {
+            GameState::Checkmate(mated) => Some(mated.opponent()),
+            _ => None,
         }
-    
-        // 2. Check rook exists
-        let rook_pos = Position::new(rook_file, rank).unwrap();
+    }
+
+    /// Returns `true` if the game ended in a stalemate or any other draw.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// board.game_state = GameState::Draw(DrawReason::FiftyMove);
+    /// assert!(board.is_draw());
+    /// ```
+    #[must_use]
+    pub fn is_draw(&self) -> bool {
+        matches!(self.game_state, GameState::Stalemate | GameState::Draw(_))
+    }
+
+    /// Ends the game as a draw by mutual agreement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// board.agree_draw();
+    /// assert_eq!(board.game_state, GameState::Draw(DrawReason::Agreement));
+    /// ```
+    pub fn agree_draw(&mut self) {
+        self.game_state = GameState::Draw(DrawReason::Agreement);
+    }
+
+    /// Returns `true` if the game is ongoing and either the fifty-move rule or threefold
+    /// repetition applies, so a draw may be claimed with [`Board::claim_draw`].
+    ///
+    /// Under [`DrawPolicy::Automatic`] such games are drawn straight away, so this is only
+    /// ever `true` with [`DrawPolicy::Claimable`] or after editing the board by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// assert!(!board.can_claim_draw());
+    /// board.halfmove_clock = 100;
+    /// assert!(board.can_claim_draw());
+    /// ```
+    #[must_use]
+    pub fn can_claim_draw(&self) -> bool {
+        self.game_state == GameState::Ongoing && self.claimable_draw().is_some()
+    }
+
+    /// Ends the game as a draw by the fifty-move rule or threefold repetition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no draw can be claimed (see [`Board::can_claim_draw`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// assert!(board.claim_draw().is_err());
+    /// board.halfmove_clock = 100;
+    /// board.claim_draw().unwrap();
+    /// assert_eq!(board.game_state, GameState::Draw(DrawReason::FiftyMove));
+    /// ```
+    pub fn claim_draw(&mut self) -> Result<(), String> {
+        match self.claimable_draw() {
+            Some(reason) if self.game_state == GameState::Ongoing => {
+                self.game_state = GameState::Draw(reason);
+                Ok(())
+            }
+            _ => Err("No draw can be claimed.".to_string()),
+        }
+    }
+
+    /// The fifty-move or repetition draw available in the current position, if any.
+    fn claimable_draw(&self) -> Option<DrawReason> {
+        if self.halfmove_clock >= 100 {
+            Some(DrawReason::FiftyMove)
+        } else if self.is_threefold_repetition() {
+            Some(DrawReason::ThreefoldRepetition)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the current position has occurred at least three times.
+    ///
+    /// Positions are compared by piece placement, side to move, castling rights
+    /// and en passant target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let board = Board::new();
+    /// assert!(!board.is_threefold_repetition());
+    /// ```
+    #[must_use]
+    pub fn is_threefold_repetition(&self) -> bool {
+        let current = self.position_key();
+        self.position_history.iter().filter(|key| **key == current).count() >= 3
+    }
+
+    /// Returns `true` if neither side has enough material to deliver checkmate.
+    ///
+    /// Covers king versus king, king and a single minor piece versus king, and
+    /// king and bishop versus king and bishop with both bishops on the same square color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// board.initialize_custom(
+    ///     vec![('e', 1, Color::White, PieceType::King), ('e', 8, Color::Black, PieceType::King)],
+    ///     Color::White,
+    ///     GameState::Ongoing,
+    /// );
+    /// assert!(board.is_insufficient_material());
+    /// ```
+    #[must_use]
+    pub fn is_insufficient_material(&self) -> bool {
+        let mut minors = Vec::new();
+        for (pos, piece) in &self.squares {
+            match piece.kind {
+                PieceType::King => {}
+                PieceType::Knight | PieceType::Bishop => minors.push((*pos, *piece)),
+                _ => return false,
+            }
+        }
+        match minors.as_slice() {
+            [] | [_] => true,
+            [(pos_a, a), (pos_b, b)] => {
+                a.kind == PieceType::Bishop
+                    && b.kind == PieceType::Bishop
+                    && a.color != b.color
+                    && pos_a.is_light() == pos_b.is_light()
+            }
+            _ => false,
+        }
+    }
+
+    /// Builds a key identifying the position for repetition detection.
+    fn position_key(&self) -> String {
+        let mut key = String::with_capacity(72);
+        for pos in Position::all() {
+            let symbol = match self.squares.get(&pos) {
+                Some(piece) if piece.color == Color::White => piece.kind.to_char().to_ascii_uppercase(),
+                Some(piece) => piece.kind.to_char(),
+                None => '.',
+            };
+            key.push(symbol);
+        }
+        key.push(if self.turn == Color::White { 'w' } else { 'b' });
+        for right in self.castling_rights() {
+            key.push(if right { '1' } else { '0' });
+        }
+        if let Some(ep) = self.en_passant_target {
+            key.push_str(&ep.to_string());
+        }
+        key
+    }
+
+    /// Plays a move given in long algebraic (UCI) notation, such as `e2e4` or `e7e8q`.
+    ///
+    /// Castling is written as the king's move, e.g. `e1g1`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is malformed (see [`Move::from_uci`]) or the move is rejected by [`Board::try_move`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// board.make_uci_move("e2e4").unwrap();
+    /// assert!(board.make_uci_move("e7e9").is_err());
+    /// ```
+    pub fn make_uci_move(&mut self, uci: &str) -> Result<(), String> {
+        let mv = Move::from_uci(uci)?;
+        self.try_move(mv.from, mv.to, mv.promotion)
+    }
+
+    /// Renders a legal move in Standard Algebraic Notation, such as `Nf3`, `exd5`, `O-O` or `e8=Q#`.
+    ///
+    /// Pieces are disambiguated by file, then rank, then both, only when another piece of the
+    /// same kind could also reach the destination. A `+` or `#` suffix marks check or mate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the move is not legal in the current position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let board = Board::new();
+    /// assert_eq!(board.move_to_san(&Move::from_uci("g1f3").unwrap()).unwrap(), "Nf3");
+    /// ```
+    pub fn move_to_san(&self, mv: &Move) -> Result<String, String> {
+        let piece = self.squares.get(&mv.from).copied().ok_or("No piece at starting position.")?;
+        let mut after = self.clone();
+        after.try_move(mv.from, mv.to, mv.promotion)?;
+
+        let mut san = String::new();
+        if let Some(kingside) = (piece.kind == PieceType::King)
+            .then(|| self.castling_side(piece.color, mv.from, mv.to))
+            .flatten()
+        {
+            san.push_str(if kingside { "O-O" } else { "O-O-O" });
+        } else {
+            let is_capture = self.squares.contains_key(&mv.to)
+                || (piece.kind == PieceType::Pawn && self.en_passant_target == Some(mv.to));
+            if piece.kind == PieceType::Pawn {
+                if is_capture {
+                    san.push(mv.from.file);
+                }
+            } else {
+                san.push(piece.kind.to_char().to_ascii_uppercase());
+                let rivals: Vec<Position> = self
+                    .get_all_legal_moves()
+                    .into_iter()
+                    .filter(|other| other.to == mv.to && other.from != mv.from)
+                    .filter(|other| self.squares.get(&other.from).map(|p| p.kind) == Some(piece.kind))
+                    .map(|other| other.from)
+                    .collect();
+                if !rivals.is_empty() {
+                    if rivals.iter().all(|p| p.file != mv.from.file) {
+                        san.push(mv.from.file);
+                    } else if rivals.iter().all(|p| p.rank != mv.from.rank) {
+                        san.push_str(&mv.from.rank.to_string());
+                    } else {
+                        san.push_str(&mv.from.to_string());
+                    }
+                }
+            }
+            if is_capture {
+                san.push('x');
+            }
+            san.push_str(&mv.to.to_string());
+            if piece.kind == PieceType::Pawn && (mv.to.rank == 1 || mv.to.rank == 8) {
+                san.push('=');
+                san.push(mv.promotion.unwrap_or(PieceType::Queen).to_char().to_ascii_uppercase());
+            }
+        }
+
+        if after.game_state == GameState::Checkmate(piece.color.opponent()) {
+            san.push('#');
+        } else if after.is_in_check(piece.color.opponent()) {
+            san.push('+');
+        }
+        Ok(san)
+    }
+
+    /// Resolves a move in Standard Algebraic Notation against the current position.
+    ///
+    /// Check, mate and annotation suffixes (`+`, `#`, `!`, `?`) are ignored, the `=` before a
+    /// promotion piece is optional, and castling may be written with letter O or digit zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the text does not name exactly one legal move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let board = Board::new();
+    /// assert_eq!(board.parse_san("e4").unwrap(), Move::from_uci("e2e4").unwrap());
+    /// assert!(board.parse_san("e5").is_err());
+    /// ```
+    pub fn parse_san(&self, san: &str) -> Result<Move, String> {
+        let normalize = |text: &str| -> String {
+            text.trim_end_matches(['+', '#', '!', '?'])
+                .replace('0', "O")
+                .replace('=', "")
+        };
+        let wanted = normalize(san.trim());
+        let mut found = None;
+        for mv in self.get_all_legal_moves() {
+            if normalize(&self.move_to_san(&mv)?) == wanted {
+                if found.is_some() {
+                    return Err(format!("Ambiguous SAN move '{san}'."));
+                }
+                found = Some(mv);
+            }
+        }
+        found.ok_or_else(|| format!("Illegal or unrecognized SAN move '{san}'."))
+    }
+
+    /// Trys to castle, moving the king and rook. The caller is responsible for switching the turn.
+    fn try_castle(&mut self, color: Color, kingside: bool) -> Result<(), String> {
+        let (king_from, rook_pos, king_to, rook_to) = self.castling_squares(color, kingside);
+    
+        // 1. Check permission
+        if !self.castling_right(color, kingside) {
+            return Err("Castling not allowed (king or rook has moved)".to_string());
+        }
+    
+        // 2. Check rook exists
         match self.squares.get(&rook_pos) {
             Some(piece) if piece.color == color && piece.kind == PieceType::Rook => {},
             _ => return Err("Rook missing for castling".to_string()),
         }
     
         // 3. Check squares between king and rook are empty
-        let files_between: Vec<char> = if kingside { vec!['f', 'g'] } else { vec!['b', 'c', 'd'] };
-        for file in files_between.iter() {
-            let pos = Position::new(*file, rank).unwrap();
-            if self.squares.contains_key(&pos) {
-                return Err("Cannot castle: path blocked".to_string());
-            }
+        if !self.castling_path_clear(color, kingside) {
+            return Err("Cannot castle: path blocked".to_string());
         }
     
         // 4. Check king is not in check and doesn't cross check
         if self.is_in_check(color) {
             return Err("Cannot castle while in check".to_string());
         }
-        let passing_files = if kingside { ['f', 'g'] } else { ['d', 'c'] };
-        for file in passing_files.iter() {
-            let mut clone = self.clone();
-            clone.force_move(king_from, Position::new(*file, rank).unwrap())?;
-            if clone.is_in_check(color) {
+        let opponent = color.opponent();
+        let (low, high) = Self::file_span(king_from.file, king_to.file);
+        for file in low..=high {
+            if self.is_square_attacked(Position { file, rank: king_from.rank }, opponent) {
                 return Err("Cannot castle through check".to_string());
             }
         }
+        let mut clone = self.clone();
+        clone.take(king_from);
+        clone.take(rook_pos);
+        clone.put(king_to, Piece { color, kind: PieceType::King });
+        clone.put(rook_to, Piece { color, kind: PieceType::Rook });
+        if clone.is_in_check(color) {
+            return Err("Cannot castle into check".to_string());
+        }
     
         // 5. Move king and rook
-        self.squares.remove(&king_from).unwrap();
-        self.squares.remove(&rook_pos).unwrap();
-        self.squares.insert(king_to, Piece { color, kind: PieceType::King });
-        self.squares.insert(rook_to, Piece { color, kind: PieceType::Rook });
+        self.take(king_from);
+        self.take(rook_pos);
+        self.put(king_to, Piece { color, kind: PieceType::King });
+        self.put(rook_to, Piece { color, kind: PieceType::Rook });
     
         // 6. Disable future castling
         match color {
@@ -406,26 +1255,158 @@ impl Board {
             }
         }
     
-        // Switch turn
-        self.turn = Self::opponent_color(self.turn);
-    
         Ok(())
     }
 
-    /// Returns the color opposite of that which is passed in.
-    fn opponent_color(color: Color) -> Color {
+    /// All four castling rights: White kingside, White queenside, Black kingside, Black queenside.
+    fn castling_rights(&self) -> [bool; 4] {
+        [
+            self.white_can_castle_kingside,
+            self.white_can_castle_queenside,
+            self.black_can_castle_kingside,
+            self.black_can_castle_queenside,
+        ]
+    }
+
+    /// Returns whether `color` still holds the castling right on the given side.
+    fn castling_right(&self, color: Color, kingside: bool) -> bool {
+        match (color, kingside) {
+            (Color::White, true) => self.white_can_castle_kingside,
+            (Color::White, false) => self.white_can_castle_queenside,
+            (Color::Black, true) => self.black_can_castle_kingside,
+            (Color::Black, false) => self.black_can_castle_queenside,
+        }
+    }
+
+    /// Returns the king origin, rook origin, king destination and rook destination for a castling move.
+    ///
+    /// The destinations are the same as in standard chess (g/f kingside, c/d queenside), while the
+    /// origins follow the board's configured start files so Chess960 positions are supported.
+    fn castling_squares(&self, color: Color, kingside: bool) -> (Position, Position, Position, Position) {
+        let rank = match color {
+            Color::White => 1,
+            Color::Black => 8,
+        };
+        let (rook_file, king_to, rook_to) = if kingside {
+            (self.kingside_rook_file, 'g', 'f')
+        } else {
+            (self.queenside_rook_file, 'c', 'd')
+        };
+        (
+            Position { file: self.king_start_file, rank },
+            Position { file: rook_file, rank },
+            Position { file: king_to, rank },
+            Position { file: rook_to, rank },
+        )
+    }
+
+    /// Returns `true` if every square the king and rook travel over is empty, ignoring the two castling pieces.
+    fn castling_path_clear(&self, color: Color, kingside: bool) -> bool {
+        let (king_from, rook_from, king_to, rook_to) = self.castling_squares(color, kingside);
+        let files = [king_from.file, rook_from.file, king_to.file, rook_to.file];
+        let low = *files.iter().min().expect("four files");
+        let high = *files.iter().max().expect("four files");
+        (low..=high)
+            .map(|file| Position { file, rank: king_from.rank })
+            .filter(|pos| *pos != king_from && *pos != rook_from)
+            .all(|pos| !self.squares.contains_key(&pos))
+    }
+
+    /// Works out whether a king move from `from` to `to` is a castling request.
+    ///
+    /// Castling may be written as the king moving onto its own castling rook (the Chess960
+    /// convention), or as the king moving to its destination square when that is at least two
+    /// files away (the standard `e1g1` form). Returns `Some(true)` for kingside, `Some(false)`
+    /// for queenside, and `None` for ordinary king moves.
+    fn castling_side(&self, color: Color, from: Position, to: Position) -> Option<bool> {
+        for kingside in [true, false] {
+            let (king_from, rook_from, king_to, _) = self.castling_squares(color, kingside);
+            if from != king_from || to.rank != king_from.rank {
+                continue;
+            }
+            let onto_own_rook = to == rook_from
+                && self.squares.get(&rook_from) == Some(&Piece { color, kind: PieceType::Rook });
+            let long_king_move = to == king_to && (king_from.file as u8).abs_diff(king_to.file as u8) >= 2;
+            if onto_own_rook || long_king_move {
+                return Some(kingside);
+            }
+        }
+        None
+    }
+
+    /// Returns the lower and upper of two files.
+    fn file_span(a: char, b: char) -> (char, char) {
+        if a <= b { (a, b) } else { (b, a) }
+    }
+
+    /// Bit for a square in the occupancy bitboards (a1 = bit 0, h8 = bit 63).
+    fn square_bit(pos: Position) -> u64 {
+        1 << ((u32::from(pos.rank) - 1) * 8 + (u32::from(pos.file) - u32::from('a')))
+    }
+
+    /// Index of a color in the occupancy bitboards.
+    fn color_index(color: Color) -> usize {
+        match color {
+            Color::White => 0,
+            Color::Black => 1,
+        }
+    }
+
+    /// Rebuilds the occupancy bitboards and king positions from `squares`.
+    fn sync_caches(&mut self) {
+        self.occupancy = [0; 2];
+        self.white_king = None;
+        self.black_king = None;
+        for (pos, piece) in &self.squares {
+            self.occupancy[Self::color_index(piece.color)] |= Self::square_bit(*pos);
+            match (piece.kind, piece.color) {
+                (PieceType::King, Color::White) => self.white_king = Some(*pos),
+                (PieceType::King, Color::Black) => self.black_king = Some(*pos),
+                _ => {}
+            }
+        }
+    }
+
+    /// The cached king position for a color.
+    fn king_slot(&mut self, color: Color) -> &mut Option<Position> {
         match color {
-            Color::White => Color::Black,
-            Color::Black => Color::White,
+            Color::White => &mut self.white_king,
+            Color::Black => &mut self.black_king,
+        }
+    }
+
+    /// Returns `true` if any piece stands on `pos`, according to the occupancy bitboards.
+    fn is_occupied(&self, pos: Position) -> bool {
+        (self.occupancy[0] | self.occupancy[1]) & Self::square_bit(pos) != 0
+    }
+
+    /// Places a piece, replacing anything on the square and keeping the caches in sync.
+    fn put(&mut self, pos: Position, piece: Piece) {
+        self.take(pos);
+        self.occupancy[Self::color_index(piece.color)] |= Self::square_bit(pos);
+        if piece.kind == PieceType::King {
+            *self.king_slot(piece.color) = Some(pos);
         }
+        self.squares.insert(pos, piece);
+    }
+
+    /// Removes and returns the piece on a square, keeping the caches in sync.
+    fn take(&mut self, pos: Position) -> Option<Piece> {
+        let bit = Self::square_bit(pos);
+        self.occupancy[0] &= !bit;
+        self.occupancy[1] &= !bit;
+        let piece = self.squares.remove(&pos)?;
+        if piece.kind == PieceType::King && *self.king_slot(piece.color) == Some(pos) {
+            *self.king_slot(piece.color) = None;
+        }
+        Some(piece)
     }
 
     /// Moves a piece from one square to another without legality checks.
     /// Used internally for simulating moves.
     fn force_move(&mut self, from: Position, to: Position) -> Result<(), String> {
-        let piece = match self.squares.remove(&from) {
-            Some(p) => p,
-            None => return Err("No piece at starting position.".to_string()),
+        let Some(piece) = self.take(from) else {
+            return Err("No piece at starting position.".to_string());
         };
         // Handle en passant capture
         if let Some(en_passant_pos) = self.en_passant_target {
@@ -433,42 +1414,137 @@ impl Board {
                 // Capturing pawn's move matches en passant square
                 let captured_pawn_rank = if piece.color == Color::White { to.rank - 1 } else { to.rank + 1 };
                 let captured_pawn_pos = Position::new(to.file, captured_pawn_rank).unwrap();
-                self.squares.remove(&captured_pawn_pos);
+                self.take(captured_pawn_pos);
             }
         }
-        self.squares.insert(to, piece);
+        self.put(to, piece);
         // Disable castling rights
-        if let Some(moved_piece) = self.squares.get(&to) {
-            if moved_piece.kind == PieceType::King {
-                match moved_piece.color {
-                    Color::White => {
-                        self.white_can_castle_kingside = false;
-                        self.white_can_castle_queenside = false;
-                    }
-                    Color::Black => {
-                        self.black_can_castle_kingside = false;
-                        self.black_can_castle_queenside = false;
-                    }
-                }
-            }
-            if moved_piece.kind == PieceType::Rook {
-                if from.file == 'a' && from.rank == 1 {
-                    self.white_can_castle_queenside = false;
-                }
-                if from.file == 'h' && from.rank == 1 {
+        self.revoke_castling_rights(piece, from);
+        Ok(())
+    }
+
+    /// Clears the castling rights lost by moving `piece` away from `from`.
+    fn revoke_castling_rights(&mut self, piece: Piece, from: Position) {
+        if piece.kind == PieceType::King {
+            match piece.color {
+                Color::White => {
                     self.white_can_castle_kingside = false;
+                    self.white_can_castle_queenside = false;
                 }
-                if from.file == 'a' && from.rank == 8 {
-                    self.black_can_castle_queenside = false;
-                }
-                if from.file == 'h' && from.rank == 8 {
+                Color::Black => {
                     self.black_can_castle_kingside = false;
+                    self.black_can_castle_queenside = false;
                 }
             }
         }
-        Ok(())
+        if piece.kind == PieceType::Rook {
+            if from.file == self.queenside_rook_file && from.rank == 1 {
+                self.white_can_castle_queenside = false;
+            }
+            if from.file == self.kingside_rook_file && from.rank == 1 {
+                self.white_can_castle_kingside = false;
+            }
+            if from.file == self.queenside_rook_file && from.rank == 8 {
+                self.black_can_castle_queenside = false;
+            }
+            if from.file == self.kingside_rook_file && from.rank == 8 {
+                self.black_can_castle_kingside = false;
+            }
+        }
+    }
+
+    /// Plays a move without legality checks, returning what is needed to take it back.
+    ///
+    /// Handles castling, en passant and promotion, updates castling rights and the en passant
+    /// target, and switches the turn. The clock, history and game state are left untouched.
+    /// Returns `None` if there is no piece on the starting square.
+    fn make(&mut self, mv: Move) -> Option<Undo> {
+        let moved = *self.squares.get(&mv.from)?;
+        let mut undo = Undo {
+            from: mv.from,
+            to: mv.to,
+            moved,
+            captured: None,
+            castle: None,
+            castling_rights: self.castling_rights(),
+            en_passant_target: self.en_passant_target,
+            turn: self.turn,
+        };
+
+        let castling = (moved.kind == PieceType::King)
+            .then(|| self.castling_side(moved.color, mv.from, mv.to))
+            .flatten();
+        if let Some(kingside) = castling {
+            let (king_from, rook_from, king_to, rook_to) = self.castling_squares(moved.color, kingside);
+            let rook = self.take(rook_from)?;
+            self.take(king_from);
+            self.put(king_to, moved);
+            self.put(rook_to, rook);
+            undo.castle = Some((king_to, rook_from, rook_to));
+        } else {
+            let en_passant = moved.kind == PieceType::Pawn && self.en_passant_target == Some(mv.to);
+            let captured_at = if en_passant { Position { file: mv.to.file, rank: mv.from.rank } } else { mv.to };
+            undo.captured = self.take(captured_at).map(|piece| (captured_at, piece));
+            self.take(mv.from);
+            let promotes = moved.kind == PieceType::Pawn && (mv.to.rank == 1 || mv.to.rank == 8);
+            let kind = if promotes { mv.promotion.unwrap_or(PieceType::Queen) } else { moved.kind };
+            self.put(mv.to, Piece { color: moved.color, kind });
+        }
+        self.revoke_castling_rights(moved, mv.from);
+
+        self.en_passant_target = None;
+        if moved.kind == PieceType::Pawn && mv.from.rank.abs_diff(mv.to.rank) == 2 {
+            let rank = u8::midpoint(mv.from.rank, mv.to.rank);
+            self.en_passant_target = Some(Position { file: mv.from.file, rank });
+        }
+        self.turn = self.turn.opponent();
+        Some(undo)
+    }
+
+    /// Takes back a move played with [`Board::make`].
+    fn unmake(&mut self, undo: Undo) {
+        if let Some((king_to, rook_from, rook_to)) = undo.castle {
+            let rook = self.take(rook_to);
+            self.take(king_to);
+            self.put(undo.from, undo.moved);
+            if let Some(rook) = rook {
+                self.put(rook_from, rook);
+            }
+        } else {
+            self.take(undo.to);
+            self.put(undo.from, undo.moved);
+            if let Some((pos, piece)) = undo.captured {
+                self.put(pos, piece);
+            }
+        }
+        [
+            self.white_can_castle_kingside,
+            self.white_can_castle_queenside,
+            self.black_can_castle_kingside,
+            self.black_can_castle_queenside,
+        ] = undo.castling_rights;
+        self.en_passant_target = undo.en_passant_target;
+        self.turn = undo.turn;
     }
     
+    /// Returns the square of the king of the given color, or `None` if it has no king.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::{Board, Color, Position};
+    /// let board = Board::new();
+    /// assert_eq!(board.find_king(Color::White), Position::new('e', 1));
+    /// assert_eq!(Board::empty().find_king(Color::Black), None);
+    /// ```
+    #[must_use]
+    pub fn find_king(&self, color: Color) -> Option<Position> {
+        match color {
+            Color::White => self.white_king,
+            Color::Black => self.black_king,
+        }
+    }
+
     /// Determines whether the player of the given color is currently in check.
     ///
     /// `is_in_check` checks if the king of the specified [`Color`] is under attack
@@ -520,8 +1596,7 @@ impl Board {
     ///
     /// # Notes
     ///
-    /// - `is_in_check` assumes that both players' kings are present on the board.
-    ///   If a king is missing (invalid game state), behavior is undefined.
+    /// - If the king of `color` is missing (see [`Board::find_king`]), this returns `false`.
     /// - This method does not detect checkmate or stalemate.
     ///   It only indicates whether the king is immediately threatened.
     /// - Used internally by [`Board::try_move`] to ensure moves do not leave a player in check.
@@ -532,17 +1607,14 @@ impl Board {
     /// - [`Board::try_move`] — Attempts a move while enforcing that the king cannot move into or remain in check.
     ///
     pub fn is_in_check(&self, color: Color) -> bool {
-        let king_pos = match self.squares.iter()
-            .find(|(_, piece)| piece.color == color && piece.kind == PieceType::King)
-        {
-            Some((pos, _)) => pos,
-            None => return false, // No king found; technically invalid game state
+        let Some(king_pos) = self.find_king(color) else {
+            return false; // No king found; technically invalid game state
         };
 
         for (pos, piece) in &self.squares {
             if piece.color != color {
                 let moves = self.get_legal_moves(*pos);
-                if moves.contains(king_pos) {
+                if moves.contains(&king_pos) {
                     return true;
                 }
             }
@@ -551,7 +1623,141 @@ impl Board {
         false
     }
 
-    /// Determines whether the player of the given color is currently checkmated.
+    /// Returns the squares of every piece of color `by` that attacks `pos`.
+    ///
+    /// Attacks follow capture rules rather than movement rules: pawns attack diagonally
+    /// forward, knights jump, sliding pieces stop at the first blocker, and kings attack
+    /// adjacent squares. The square `pos` itself may be empty or occupied by either color.
+    ///
+    /// # Arguments
+    ///
+    /// - `pos` — The square being attacked.
+    /// - `by` — The [`Color`] of the attacking side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let board = Board::new();
+    /// // f3 is covered by the g1 knight and the e2 and g2 pawns
+    /// let attackers = board.attackers_of(Position::new('f', 3).unwrap(), Color::White);
+    /// assert_eq!(attackers.len(), 3);
+    /// ```
+    #[must_use]
+    pub fn attackers_of(&self, pos: Position, by: Color) -> Vec<Position> {
+        let offset = |df: i8, dr: i8| pos.offset(df, dr);
+        let holds = |square: Position, kinds: &[PieceType]| {
+            self.squares
+                .get(&square)
+                .is_some_and(|piece| piece.color == by && kinds.contains(&piece.kind))
+        };
+        let mut attackers = Vec::new();
+
+        // Pawns attack diagonally forward, so look one rank behind the target
+        let pawn_rank = match by {
+            Color::White => -1,
+            Color::Black => 1,
+        };
+        for df in [-1, 1] {
+            if let Some(square) = offset(df, pawn_rank) {
+                if holds(square, &[PieceType::Pawn]) {
+                    attackers.push(square);
+                }
+            }
+        }
+
+        let knight_jumps = [(2, 1), (1, 2), (-1, 2), (-2, 1), (-2, -1), (-1, -2), (1, -2), (2, -1)];
+        for (df, dr) in knight_jumps {
+            if let Some(square) = offset(df, dr) {
+                if holds(square, &[PieceType::Knight]) {
+                    attackers.push(square);
+                }
+            }
+        }
+
+        let king_steps = [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (-1, 1), (1, -1), (-1, -1)];
+        for (df, dr) in king_steps {
+            if let Some(square) = offset(df, dr) {
+                if holds(square, &[PieceType::King]) {
+                    attackers.push(square);
+                }
+            }
+        }
+
+        let straight = [PieceType::Rook, PieceType::Queen];
+        let diagonal = [PieceType::Bishop, PieceType::Queen];
+        for (df, dr) in king_steps {
+            let sliders: &[PieceType] = if df == 0 || dr == 0 { &straight } else { &diagonal };
+            let mut distance = 1;
+            while let Some(square) = offset(df * distance, dr * distance) {
+                if self.is_occupied(square) {
+                    if holds(square, sliders) {
+                        attackers.push(square);
+                    }
+                    break;
+                }
+                distance += 1;
+            }
+        }
+
+        attackers
+    }
+
+    /// Returns `true` if any piece of color `by` attacks `pos`.
+    ///
+    /// See [`Board::attackers_of`] for the attack rules used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let board = Board::new();
+    /// assert!(board.is_square_attacked(Position::new('e', 3).unwrap(), Color::White));
+    /// assert!(!board.is_square_attacked(Position::new('e', 4).unwrap(), Color::White));
+    /// ```
+    #[must_use]
+    pub fn is_square_attacked(&self, pos: Position, by: Color) -> bool {
+        !self.attackers_of(pos, by).is_empty()
+    }
+
+    /// Returns the squares of pieces of `color` that are attacked by the opponent and not
+    /// defended by any friendly piece, in [`Position::all`] order.
+    ///
+    /// The king is never reported, since it cannot be captured.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// board.initialize_custom(
+    ///     vec![
+    ///         ('e', 1, Color::White, PieceType::King),
+    ///         ('d', 4, Color::White, PieceType::Knight),
+    ///         ('d', 8, Color::Black, PieceType::Rook),
+    ///         ('h', 8, Color::Black, PieceType::King),
+    ///     ],
+    ///     Color::White,
+    ///     GameState::Ongoing,
+    /// );
+    /// assert_eq!(board.threatened_pieces(Color::White), vec![Position::new('d', 4).unwrap()]);
+    /// ```
+    #[must_use]
+    pub fn threatened_pieces(&self, color: Color) -> Vec<Position> {
+        Position::all()
+            .filter(|&pos| {
+                self.squares
+                    .get(&pos)
+                    .is_some_and(|piece| piece.color == color && piece.kind != PieceType::King)
+            })
+            .filter(|&pos| self.is_square_attacked(pos, color.opponent()) && !self.is_square_attacked(pos, color))
+            .collect()
+    }
+
+    /// Determines whether the player of the given color is currently checkmated.
     ///
     /// `is_checkmate` checks if the king of the specified [`Color`] is under attack (in check),
     /// and whether there are no legal moves available to escape the threat.
@@ -624,6 +1830,7 @@ impl Board {
             return false;
         }
     
+        let mut scratch = self.clone();
         for (from, piece) in &self.squares {
             if piece.color != color {
                 continue;
@@ -631,8 +1838,7 @@ impl Board {
     
             let legal_moves = self.get_legal_moves(*from);
             for to in legal_moves {
-                let mut cloned = self.clone();
-                if cloned.force_move(*from, to).is_ok() && !cloned.is_in_check(color) {
+                if scratch.is_move_safe(*from, to) {
                     return false;
                 }
             }
@@ -703,6 +1909,7 @@ impl Board {
             return false;
         }
 
+        let mut scratch = self.clone();
         for (from, piece) in &self.squares {
             if piece.color != color {
                 continue;
@@ -710,8 +1917,7 @@ impl Board {
 
             let legal_moves = self.get_legal_moves(*from);
             for to in legal_moves {
-                let mut cloned = self.clone();
-                if cloned.force_move(*from, to).is_ok() && !cloned.is_in_check(color) {
+                if scratch.is_move_safe(*from, to) {
                     return false;
                 }
             }
@@ -720,6 +1926,40 @@ impl Board {
         true
     }
 
+    /// Returns `true` if the side to move is checkmated; shorthand for
+    /// `board.is_checkmate(board.turn)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mut board = Board::new();
+    /// for mv in ["f2f3", "e7e5", "g2g4", "d8h4"] {
+    ///     board.make_uci_move(mv).unwrap();
+    /// }
+    /// assert!(board.side_to_move_checkmated());
+    /// ```
+    #[must_use]
+    pub fn side_to_move_checkmated(&self) -> bool {
+        self.is_checkmate(self.turn)
+    }
+
+    /// Returns `true` if the side to move is stalemated; shorthand for
+    /// `board.is_stalemate(board.turn)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// assert!(!Board::new().side_to_move_stalemated());
+    /// ```
+    #[must_use]
+    pub fn side_to_move_stalemated(&self) -> bool {
+        self.is_stalemate(self.turn)
+    }
+
     /// Returns all legal moves for the piece at a given position, based on standard chess rules.
     ///
     /// `get_legal_moves` calculates the set of valid destination squares for the piece located
@@ -865,9 +2105,7 @@ impl Board {
                 ];
 
                 for (df, dr) in &knight_moves {
-                    let next_file = (from.file as u8 as i8 + df) as u8 as char;
-                    let next_rank = (from.rank as i8 + dr) as u8;
-                    if let Some(pos) = Position::new(next_file, next_rank) {
+                    if let Some(pos) = from.offset(*df, *dr) {
                         if !self.squares.get(&pos).map_or(false, |p| p.color == piece.color) {
                             moves.push(pos);
                         }
@@ -882,47 +2120,27 @@ impl Board {
                 ];
             
                 for (df, dr) in &king_moves {
-                    let next_file = (from.file as u8 as i8 + df) as u8 as char;
-                    let next_rank = (from.rank as i8 + dr) as u8;
-                    if let Some(pos) = Position::new(next_file, next_rank) {
+                    if let Some(pos) = from.offset(*df, *dr) {
                         if !self.squares.get(&pos).map_or(false, |p| p.color == piece.color) {
                             moves.push(pos);
                         }
                     }
                 }
             
-                // Castling moves
-                let rank = match piece.color {
-                    Color::White => 1,
-                    Color::Black => 8,
-                };
-            
-                // Only if king is on original square
-                if from == Position::new('e', rank).unwrap() {
-                    // Kingside castling
-                    let kingside_allowed = match piece.color {
-                        Color::White => self.white_can_castle_kingside,
-                        Color::Black => self.black_can_castle_kingside,
-                    };
-                    if kingside_allowed {
-                        let f_pos = Position::new('f', rank).unwrap();
-                        let g_pos = Position::new('g', rank).unwrap();
-                        if self.squares.get(&f_pos).is_none() && self.squares.get(&g_pos).is_none() {
-                            moves.push(g_pos);
-                        }
-                    }
-            
-                    // Queenside castling
-                    let queenside_allowed = match piece.color {
-                        Color::White => self.white_can_castle_queenside,
-                        Color::Black => self.black_can_castle_queenside,
-                    };
-                    if queenside_allowed {
-                        let b_pos = Position::new('b', rank).unwrap();
-                        let c_pos = Position::new('c', rank).unwrap();
-                        let d_pos = Position::new('d', rank).unwrap();
-                        if self.squares.get(&b_pos).is_none() && self.squares.get(&c_pos).is_none() && self.squares.get(&d_pos).is_none() {
-                            moves.push(c_pos);
+                // Castling moves, only if king is on original square
+                for kingside in [true, false] {
+                    let (king_from, rook_from, king_to, _) = self.castling_squares(piece.color, kingside);
+                    let rook_in_place = self.squares.get(&rook_from) == Some(&Piece { color: piece.color, kind: PieceType::Rook });
+                    if from == king_from
+                        && self.castling_right(piece.color, kingside)
+                        && rook_in_place
+                        && self.castling_path_clear(piece.color, kingside)
+                    {
+                        // Short king hops are written as the king moving onto its rook
+                        if (king_from.file as u8).abs_diff(king_to.file as u8) >= 2 {
+                            moves.push(king_to);
+                        } else {
+                            moves.push(rook_from);
                         }
                     }
                 }
@@ -931,182 +2149,723 @@ impl Board {
 
         moves
     }
-    /// Move outward in given directions until blocked.
-    fn moves_in_directions(&self, from: Position, directions: &[(i8, i8)], color: Color) -> Vec<Position> {
-        let mut moves = Vec::new();
-
-        for (df, dr) in directions {
-            let mut next_file = from.file as u8 as i8;
-            let mut next_rank = from.rank as i8;
 
-            loop {
-                next_file += df;
-                next_rank += dr;
-
-                if !(b'a' as i8 <= next_file && next_file <= b'h' as i8 && 1 <= next_rank && next_rank <= 8) {
-                    break;
-                }
-
-                let pos = Position {
-                    file: next_file as u8 as char,
-                    rank: next_rank as u8,
-                };
-
-                if let Some(other_piece) = self.squares.get(&pos) {
-                    if other_piece.color != color {
-                        moves.push(pos); // capture
-                    }
-                    break; // blocked
-                } else {
-                    moves.push(pos);
-                }
-            }
+    /// Counts the pieces of `color` by type. Every [`PieceType`] has an entry, even when the
+    /// count is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let counts = Board::new().piece_counts(Color::Black);
+    /// assert_eq!(counts[&PieceType::Pawn], 8);
+    /// assert_eq!(counts[&PieceType::Queen], 1);
+    /// ```
+    #[must_use]
+    pub fn piece_counts(&self, color: Color) -> HashMap<PieceType, usize> {
+        let mut counts: HashMap<PieceType, usize> = PieceType::all().into_iter().map(|kind| (kind, 0)).collect();
+        for piece in self.squares.values().filter(|piece| piece.color == color) {
+            *counts.entry(piece.kind).or_default() += 1;
         }
-
-        moves
+        counts
     }
 
-    /// Displays the current board state as a text-based chessboard in the console.
-    ///
-    /// `display` prints a simple, human-readable representation of the chess board,
-    /// showing the position of all pieces and empty squares. The board is printed
-    /// with rank numbers (8 to 1) on the left and file letters (a to h) on the bottom,
-    /// consistent with standard chess notation.
-    ///
-    /// Empty squares are represented with a dot (`.`).
-    /// White and black pieces are represented using Unicode chess symbols.
+    /// Returns the pieces on `rank`, ordered from file a to file h.
     ///
-    /// # Behavior
+    /// An out-of-range rank yields an empty list.
     ///
-    /// - The board is printed with rank 8 at the top and rank 1 at the bottom.
-    /// - Each piece is rendered as a distinct Unicode symbol based on its color and type.
-    /// - Empty squares are displayed as dots (`.`).
-    /// - The console output is intended for human reading and debugging, not for parsing by other programs.
+    /// # Examples
     ///
-    /// # Example Output
+    /// ```
+    /// use puzzle_engine::chess::*;
     ///
-    /// ```text
-    /// 8 ♜ ♞ ♝ ♛ ♚ ♝ ♞ ♜ 
-    /// 7 ♟ ♟ ♟ ♟ ♟ ♟ ♟ ♟ 
-    /// 6 .  .  .  .  .  .  .  . 
-    /// 5 .  .  .  .  .  .  .  . 
-    /// 4 .  .  .  .  .  .  .  . 
-    /// 3 .  .  .  .  .  .  .  . 
-    /// 2 ♙ ♙ ♙ ♙ ♙ ♙ ♙ ♙ 
-    /// 1 ♖ ♘ ♗ ♕ ♔ ♗ ♘ ♖ 
-    ///    a  b  c  d  e  f  g  h
+    /// let board = Board::new();
+    /// assert_eq!(board.rank_pieces(2).len(), 8);
+    /// assert!(board.rank_pieces(4).is_empty());
     /// ```
+    #[must_use]
+    pub fn rank_pieces(&self, rank: u8) -> Vec<(Position, Piece)> {
+        ('a'..='h')
+            .filter_map(|file| Position::new(file, rank))
+            .filter_map(|pos| self.squares.get(&pos).map(|piece| (pos, *piece)))
+            .collect()
+    }
+
+    /// Returns the pieces on `file`, ordered from rank 1 to rank 8.
+    ///
+    /// An out-of-range file yields an empty list.
     ///
     /// # Examples
     ///
     /// ```
-    /// use puzzle_engine::chess::Board;
+    /// use puzzle_engine::chess::*;
     ///
     /// let board = Board::new();
-    /// board.display();
+    /// let e_file = board.file_pieces('e');
+    /// assert_eq!(e_file.len(), 4);
+    /// assert_eq!(e_file[0].1, Piece { color: Color::White, kind: PieceType::King });
     /// ```
+    #[must_use]
+    pub fn file_pieces(&self, file: char) -> Vec<(Position, Piece)> {
+        (1..=8)
+            .filter_map(|rank| Position::new(file, rank))
+            .filter_map(|pos| self.squares.get(&pos).map(|piece| (pos, *piece)))
+            .collect()
+    }
+
+    /// Reports the doubled, isolated and passed pawns of `color`.
     ///
-    /// # Notes
-    ///
-    /// - `display` writes directly to standard output (`stdout`).
-    /// - The output format is intended for visualization and debugging purposes only.
-    /// - Unicode chess symbols may not display correctly in some terminals that lack full Unicode support.
-    /// - This method does not return a value; it purely outputs text to the console.
+    /// See [`PawnStructure`] for the definitions used.
     ///
-    /// # See Also
+    /// # Examples
     ///
-    /// - For programmatic access to the board's contents, use the [`Board::squares`] field.
-    /// - For custom rendering, consider using [`Board::write_display`] which allows writing to any formatter.
+    /// ```
+    /// use puzzle_engine::chess::*;
     ///
-    pub fn display(&self) {
-        let mut output = String::new();
-        self.write_display(&mut output).unwrap();
-        println!("{}", output);
+    /// let board = Board::new();
+    /// assert_eq!(board.pawn_structure(Color::White), PawnStructure::default());
+    /// ```
+    #[must_use]
+    pub fn pawn_structure(&self, color: Color) -> PawnStructure {
+        // Pawn ranks on each file, indexed from file a
+        let pawn_ranks = |side: Color| -> Vec<Vec<u8>> {
+            ('a'..='h')
+                .map(|file| {
+                    self.file_pieces(file)
+                        .into_iter()
+                        .filter(|(_, piece)| piece.color == side && piece.kind == PieceType::Pawn)
+                        .map(|(pos, _)| pos.rank)
+                        .collect()
+                })
+                .collect()
+        };
+        let own = pawn_ranks(color);
+        let enemy = pawn_ranks(color.opponent());
+        let adjacent = |file: usize| file.saturating_sub(1)..=(file + 1).min(7);
+
+        let mut structure = PawnStructure::default();
+        for (file, ranks) in own.iter().enumerate() {
+            structure.doubled += ranks.len().saturating_sub(1);
+            if adjacent(file).all(|other| other == file || own[other].is_empty()) {
+                structure.isolated += ranks.len();
+            }
+            for &rank in ranks {
+                let blocked = adjacent(file).any(|other| {
+                    enemy[other].iter().any(|&enemy_rank| match color {
+                        Color::White => enemy_rank > rank,
+                        Color::Black => enemy_rank < rank,
+                    })
+                });
+                if !blocked {
+                    structure.passed += 1;
+                }
+            }
+        }
+        structure
     }
-    
-    /// Writes the current board state to a given formatter using text-based chessboard formatting.
+
+    /// Counts the pseudo-legal destination squares of every piece of `color`.
     ///
-    /// `write_display` generates the same visual representation as [`Board::display`],
-    /// but writes the output to any type that implements [`std::fmt::Write`].
+    /// This sums [`Board::get_legal_moves`] over the side's pieces, so moves that would leave
+    /// the king in check are included. It is much cheaper than full legality filtering and is
+    /// intended as an evaluation term.
     ///
-    /// This allows greater flexibility, such as capturing the board's appearance
-    /// into a [`String`], writing to a file, or embedding into logs.
+    /// # Examples
     ///
-    /// Empty squares are represented by dots (`.`).
-    /// Pieces are rendered using Unicode chess symbols, with rank numbers (8 to 1) and file letters (a to h).
+    /// ```
+    /// use puzzle_engine::chess::*;
     ///
-    /// # Arguments
+    /// let board = Board::new();
+    /// assert_eq!(board.mobility(Color::White), 20);
+    /// ```
+    #[must_use]
+    pub fn mobility(&self, color: Color) -> usize {
+        self.squares
+            .iter()
+            .filter(|(_, piece)| piece.color == color)
+            .map(|(pos, _)| self.get_legal_moves(*pos).len())
+            .sum()
+    }
+
+    /// Returns every fully legal move for the side to move.
     ///
-    /// - `w` — A mutable reference to any type implementing [`std::fmt::Write`] (such as [`String`] or `fmt::Formatter`).
+    /// Unlike [`Board::get_legal_moves`], moves that would leave the mover's king in check are
+    /// filtered out, and a pawn reaching the last rank yields one [`Move`] per promotion choice
+    /// (queen, rook, bishop and knight).
     ///
-    /// # Returns
+    /// # Examples
     ///
-    /// Returns [`Ok(())`] if all writes succeeded, or [`Err(std::fmt::Error)`] if a write operation failed.
+    /// ```
+    /// use puzzle_engine::chess::*;
     ///
-    /// # Behavior
+    /// let board = Board::new();
+    /// assert_eq!(board.get_all_legal_moves().len(), 20);
+    /// ```
+    #[must_use]
+    pub fn get_all_legal_moves(&self) -> Vec<Move> {
+        let mut moves = Vec::new();
+        let mut scratch = self.clone();
+        for (&from, piece) in &self.squares {
+            if piece.color != self.turn {
+                continue;
+            }
+            for to in self.get_legal_moves(from) {
+                if !scratch.is_move_safe(from, to) {
+                    continue;
+                }
+                let promotes = piece.kind == PieceType::Pawn && (to.rank == 1 || to.rank == 8);
+                if promotes {
+                    for kind in PieceType::promotion_choices() {
+                        moves.push(Move::new(from, to, Some(kind)));
+                    }
+                } else {
+                    moves.push(Move::new(from, to, None));
+                }
+            }
+        }
+        moves
+    }
+
+    /// Returns every fully legal move for the side to move, each paired with its [`MoveKind`].
     ///
-    /// - Ranks are printed from 8 (top) to 1 (bottom).
-    /// - Each piece is printed as a Unicode character corresponding to its type and color.
-    /// - Empty squares are shown as dots (`.`).
-    /// - After printing all ranks, the file letters (`a` to `h`) are printed at the bottom.
-    /// - No color highlighting or board themes are applied; the output is purely textual.
+    /// Promotions are reported as [`MoveKind::Promotion`] even when they also capture.
     ///
     /// # Examples
     ///
     /// ```
-    /// use puzzle_engine::chess::Board;
-    /// use std::fmt::Write;
+    /// use puzzle_engine::chess::*;
     ///
-    /// let board = Board::new();
-    /// let mut output = String::new();
+    /// let moves = Board::new().get_all_legal_moves_classified();
+    /// assert!(moves.iter().all(|(_, kind)| *kind == MoveKind::Quiet));
+    /// ```
+    #[must_use]
+    pub fn get_all_legal_moves_classified(&self) -> Vec<(Move, MoveKind)> {
+        self.get_all_legal_moves()
+            .into_iter()
+            .map(|mv| (mv, self.classify_move(&mv)))
+            .collect()
+    }
+
+    /// Classifies a move for the piece on `mv.from`, which must exist.
+    fn classify_move(&self, mv: &Move) -> MoveKind {
+        let piece = self.squares[&mv.from];
+        if let Some(kind) = mv.promotion {
+            return MoveKind::Promotion(kind);
+        }
+        if piece.kind == PieceType::King && self.castling_side(piece.color, mv.from, mv.to).is_some() {
+            return MoveKind::Castle;
+        }
+        if piece.kind == PieceType::Pawn && mv.from.file != mv.to.file && !self.squares.contains_key(&mv.to) {
+            return MoveKind::EnPassant;
+        }
+        match self.squares.get(&mv.to) {
+            Some(captured) => MoveKind::Capture(captured.kind),
+            None => MoveKind::Quiet,
+        }
+    }
+
+    /// Returns every fully legal move for `color` in a stable order: by from-square, then
+    /// to-square, each ordered by file and then rank. Promotions to the same square follow
+    /// [`PieceType::promotion_choices`].
     ///
-    /// board.write_display(&mut output).unwrap();
+    /// [`Board::get_all_legal_moves`] follows the iteration order of [`Board::squares`], which
+    /// varies between boards; use this when reproducible ordering matters.
+    ///
+    /// # Examples
     ///
     /// ```
+    /// use puzzle_engine::chess::*;
     ///
-    /// # Notes
+    /// let moves = Board::new().get_all_legal_moves_sorted(Color::White);
+    /// assert_eq!(moves[0].to_string(), "a2a3");
+    /// ```
+    #[must_use]
+    pub fn get_all_legal_moves_sorted(&self, color: Color) -> Vec<Move> {
+        let mut board = self.clone();
+        board.turn = color;
+        let mut moves = board.get_all_legal_moves();
+        moves.sort_by_key(|mv| (mv.from.file, mv.from.rank, mv.to.file, mv.to.rank));
+        moves
+    }
+
+    /// Returns the legal moves that get `color` out of check.
     ///
-    /// - This method provides the core logic behind [`Board::display`], but does not perform any printing itself.
-    /// - Useful for unit testing, logging, or advanced applications where direct control over the output destination is required.
-    /// - The output format matches that of [`Board::display`], ensuring consistency.
+    /// A single check can be answered by moving the king to a safe square, capturing the
+    /// checking piece, or interposing a piece on the line between a sliding checker and the
+    /// king. In double check only king moves are possible. Returns an empty list if `color`
+    /// is not in check.
     ///
-    /// # See Also
+    /// # Examples
     ///
-    /// - [`Board::display`] — Convenience method to print directly to standard output (`stdout`).
-    /// - [`std::fmt::Write`] — Trait used for the output target.
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let pieces = vec![
+    ///     ('e', 1, Color::White, PieceType::King),
+    ///     ('e', 8, Color::Black, PieceType::Rook),
+    ///     ('a', 8, Color::Black, PieceType::King),
+    /// ];
+    /// let mut board = Board::new();
+    /// board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+    /// assert!(board.check_evasions(Color::White).iter().all(|mv| mv.from == Position::new('e', 1).unwrap()));
+    /// ```
+    #[must_use]
+    pub fn check_evasions(&self, color: Color) -> Vec<Move> {
+        let Some(king) = self.find_king(color) else {
+            return Vec::new();
+        };
+        let checkers = self.attackers_of(king, color.opponent());
+        if checkers.is_empty() {
+            return Vec::new();
+        }
+        let mut board = self.clone();
+        board.turn = color;
+        // Every legal move escapes check; in double check only the king can move
+        board
+            .get_all_legal_moves()
+            .into_iter()
+            .filter(|mv| mv.from == king || checkers.len() == 1)
+            .collect()
+    }
+
+    /// Counts the leaf nodes of the legal move tree to the given depth.
+    ///
+    /// Perft ("performance test") is the standard way of checking a move generator against
+    /// known node counts. Each promotion choice counts as a separate move. The tree is walked
+    /// without adjudicating game ends, so moves below a drawn position are still counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let board = Board::new();
+    /// assert_eq!(board.perft(2), 400);
+    /// ```
+    #[must_use]
+    pub fn perft(&self, depth: u32) -> u64 {
+        self.clone().perft_nodes(depth)
+    }
+
+    /// Walks the move tree for [`Board::perft`] with make/unmake, leaving the board unchanged.
+    fn perft_nodes(&mut self, depth: u32) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        let moves = self.get_all_legal_moves();
+        if depth == 1 {
+            return moves.len() as u64;
+        }
+        moves
+            .into_iter()
+            .map(|mv| {
+                let Some(undo) = self.make(mv) else {
+                    return 0;
+                };
+                let nodes = self.perft_nodes(depth - 1);
+                self.unmake(undo);
+                nodes
+            })
+            .sum()
+    }
+
+    /// Splits [`Board::perft`] by root move: each legal move paired with the number of leaf
+    /// nodes below it at `depth - 1`.
+    ///
+    /// Comparing these counts against a reference engine narrows a move generation bug down to
+    /// a single move. Moves are in the order of [`Board::get_all_legal_moves_sorted`]. Returns an
+    /// empty list at depth 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let divide = Board::new().perft_divide(2);
+    /// assert_eq!(divide.len(), 20);
+    /// assert!(divide.iter().all(|(_, nodes)| *nodes == 20));
+    /// ```
+    #[must_use]
+    pub fn perft_divide(&self, depth: u32) -> Vec<(Move, u64)> {
+        if depth == 0 {
+            return Vec::new();
+        }
+        let mut board = self.clone();
+        self.get_all_legal_moves_sorted(self.turn)
+            .into_iter()
+            .map(|mv| {
+                let nodes = board.make(mv).map_or(0, |undo| {
+                    let nodes = board.perft_nodes(depth - 1);
+                    board.unmake(undo);
+                    nodes
+                });
+                (mv, nodes)
+            })
+            .collect()
+    }
+
+    /// Searches for a forced checkmate by `color` within `n` of its own moves.
+    ///
+    /// Every defence is considered, so a line is only returned when `color` mates however the
+    /// opponent replies. The returned line alternates attacker and defender moves, following the
+    /// first defence examined at each step, and ends with the mating move.
+    ///
+    /// Returns `None` if no forced mate exists at that depth, or if it is not `color`'s turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let pieces = vec![
+    ///     ('g', 1, Color::White, PieceType::King),
+    ///     ('a', 1, Color::White, PieceType::Rook),
+    ///     ('g', 8, Color::Black, PieceType::King),
+    ///     ('f', 7, Color::Black, PieceType::Pawn),
+    ///     ('g', 7, Color::Black, PieceType::Pawn),
+    ///     ('h', 7, Color::Black, PieceType::Pawn),
+    /// ];
+    /// let mut board = Board::new();
+    /// board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+    ///
+    /// let line = board.has_mate_in(Color::White, 1).unwrap();
+    /// assert_eq!(line[0].to_string(), "a1a8");
+    /// ```
+    #[must_use]
+    pub fn has_mate_in(&self, color: Color, n: u32) -> Option<Vec<Move>> {
+        if n == 0 || self.turn != color || self.game_state != GameState::Ongoing {
+            return None;
+        }
+        for mv in self.get_all_legal_moves() {
+            let mut next = self.clone();
+            if next.try_move(mv.from, mv.to, mv.promotion).is_err() {
+                continue;
+            }
+            if next.game_state == GameState::Checkmate(color.opponent()) {
+                return Some(vec![mv]);
+            }
+            if n == 1 || next.game_state != GameState::Ongoing {
+                continue;
+            }
+            // Every defence must still lose within the remaining moves
+            let mut line = None;
+            let mut refuted = false;
+            for reply in next.get_all_legal_moves() {
+                let mut after = next.clone();
+                if after.try_move(reply.from, reply.to, reply.promotion).is_err() {
+                    continue;
+                }
+                let Some(rest) = after.has_mate_in(color, n - 1) else {
+                    refuted = true;
+                    break;
+                };
+                line.get_or_insert_with(|| [vec![mv, reply], rest].concat());
+            }
+            if !refuted {
+                if let Some(line) = line {
+                    return Some(line);
+                }
+            }
+        }
+        None
+    }
+
+    /// Checks that moving from `from` to `to` does not leave the mover's king in check.
+    /// Castling moves are additionally checked for passing through attacked squares.
+    ///
+    /// The move is played with [`Board::make`] and taken back, so the board is unchanged afterwards.
+    fn is_move_safe(&mut self, from: Position, to: Position) -> bool {
+        let Some(piece) = self.squares.get(&from).copied() else {
+            return false;
+        };
+        if piece.kind == PieceType::King {
+            if let Some(kingside) = self.castling_side(piece.color, from, to) {
+                return self.clone().try_castle(piece.color, kingside).is_ok();
+            }
+        }
+        let Some(undo) = self.make(Move::new(from, to, None)) else {
+            return false;
+        };
+        let safe = !self.is_in_check(piece.color);
+        self.unmake(undo);
+        safe
+    }
+
+    /// Move outward in given directions until blocked.
+    fn moves_in_directions(&self, from: Position, directions: &[(i8, i8)], color: Color) -> Vec<Position> {
+        let mut moves = Vec::new();
+
+        for (df, dr) in directions {
+            let mut next_file = from.file as u8 as i8;
+            let mut next_rank = from.rank as i8;
+
+            loop {
+                next_file += df;
+                next_rank += dr;
+
+                if !(b'a' as i8 <= next_file && next_file <= b'h' as i8 && 1 <= next_rank && next_rank <= 8) {
+                    break;
+                }
+
+                let pos = Position {
+                    file: next_file as u8 as char,
+                    rank: next_rank as u8,
+                };
+
+                let bit = Self::square_bit(pos);
+                if (self.occupancy[0] | self.occupancy[1]) & bit == 0 {
+                    moves.push(pos);
+                    continue;
+                }
+                if self.occupancy[Self::color_index(color)] & bit == 0 {
+                    moves.push(pos); // capture
+                }
+                break; // blocked
+            }
+        }
+
+        moves
+    }
+
+    /// Displays the current board state as a text-based chessboard in the console.
+    ///
+    /// `display` prints a simple, human-readable representation of the chess board,
+    /// showing the position of all pieces and empty squares. The board is printed
+    /// with rank numbers (8 to 1) on the left and file letters (a to h) on the bottom,
+    /// consistent with standard chess notation.
+    ///
+    /// Empty squares are represented with a dot (`.`).
+    /// White and black pieces are represented using Unicode chess symbols.
+    ///
+    /// # Behavior
+    ///
+    /// - The board is printed with rank 8 at the top and rank 1 at the bottom.
+    /// - Each piece is rendered as a distinct Unicode symbol based on its color and type.
+    /// - Empty squares are displayed as dots (`.`).
+    /// - The console output is intended for human reading and debugging, not for parsing by other programs.
+    ///
+    /// # Example Output
+    ///
+    /// ```text
+    /// 8 ♜ ♞ ♝ ♛ ♚ ♝ ♞ ♜ 
+    /// 7 ♟ ♟ ♟ ♟ ♟ ♟ ♟ ♟ 
+    /// 6 .  .  .  .  .  .  .  . 
+    /// 5 .  .  .  .  .  .  .  . 
+    /// 4 .  .  .  .  .  .  .  . 
+    /// 3 .  .  .  .  .  .  .  . 
+    /// 2 ♙ ♙ ♙ ♙ ♙ ♙ ♙ ♙ 
+    /// 1 ♖ ♘ ♗ ♕ ♔ ♗ ♘ ♖ 
+    ///    a  b  c  d  e  f  g  h
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::Board;
+    ///
+    /// let board = Board::new();
+    /// board.display();
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - `display` writes directly to standard output (`stdout`).
+    /// - The output format is intended for visualization and debugging purposes only.
+    /// - Unicode chess symbols may not display correctly in some terminals that lack full Unicode support.
+    /// - This method does not return a value; it purely outputs text to the console.
+    ///
+    /// # See Also
+    ///
+    /// - For programmatic access to the board's contents, use the [`Board::squares`] field.
+    /// - For custom rendering, consider using [`Board::write_display`] which allows writing to any formatter.
+    ///
+    pub fn display(&self) {
+        let mut output = String::new();
+        self.write_display(&mut output).unwrap();
+        println!("{}", output);
+    }
+    
+    /// Writes the current board state to a given formatter using text-based chessboard formatting.
+    ///
+    /// `write_display` generates the same visual representation as [`Board::display`],
+    /// but writes the output to any type that implements [`std::fmt::Write`].
+    ///
+    /// This allows greater flexibility, such as capturing the board's appearance
+    /// into a [`String`], writing to a file, or embedding into logs.
+    ///
+    /// Empty squares are represented by dots (`.`).
+    /// Pieces are rendered using Unicode chess symbols, with rank numbers (8 to 1) and file letters (a to h).
+    ///
+    /// # Arguments
+    ///
+    /// - `w` — A mutable reference to any type implementing [`std::fmt::Write`] (such as [`String`] or `fmt::Formatter`).
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Ok(())`] if all writes succeeded, or [`Err(std::fmt::Error)`] if a write operation failed.
+    ///
+    /// # Behavior
+    ///
+    /// - Ranks are printed from 8 (top) to 1 (bottom).
+    /// - Each piece is printed as a Unicode character corresponding to its type and color.
+    /// - Empty squares are shown as dots (`.`).
+    /// - After printing all ranks, the file letters (`a` to `h`) are printed at the bottom.
+    /// - No color highlighting or board themes are applied; the output is purely textual.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::Board;
+    /// use std::fmt::Write;
+    ///
+    /// let board = Board::new();
+    /// let mut output = String::new();
+    ///
+    /// board.write_display(&mut output).unwrap();
+    ///
+    /// ```
+    ///
+    /// # Notes
+    ///
+    /// - This method provides the core logic behind [`Board::display`], but does not perform any printing itself.
+    /// - Useful for unit testing, logging, or advanced applications where direct control over the output destination is required.
+    /// - The output format matches that of [`Board::display`], ensuring consistency.
+    ///
+    /// # See Also
+    ///
+    /// - [`Board::display`] — Convenience method to print directly to standard output (`stdout`).
+    /// - [`std::fmt::Write`] — Trait used for the output target.
     ///
-    #[allow(clippy::missing_panics_doc)]
     pub fn write_display<W: FmtWrite>(&self, w: &mut W) -> std::fmt::Result {
-        for rank in (1..=8).rev() {
+        self.write_display_with(w, DisplayOptions::default())
+    }
+
+    /// Writes the board like [`Board::write_display`], with the look controlled by `opts`.
+    ///
+    /// Empty squares use [`DisplayOptions::light_square`] or [`DisplayOptions::dark_square`]
+    /// according to [`Position::is_light`], and with Black's orientation rank 1 is at the top
+    /// and file h on the left.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `w` fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let board = Board::empty();
+    /// let opts = DisplayOptions { light_square: '-', dark_square: '#', ..DisplayOptions::default() };
+    /// let mut output = String::new();
+    /// board.write_display_with(&mut output, opts).unwrap();
+    /// assert!(output.ends_with("1  #  -  #  -  #  -  #  - \n   a  b  c  d  e  f  g  h\n"));
+    /// ```
+    pub fn write_display_with<W: FmtWrite>(&self, w: &mut W, opts: DisplayOptions) -> std::fmt::Result {
+        let mut ranks: Vec<u8> = (1..=8).rev().collect();
+        let mut files: Vec<char> = ('a'..='h').collect();
+        if opts.orientation == Color::Black {
+            ranks.reverse();
+            files.reverse();
+        }
+        for &rank in &ranks {
             write!(w, "{rank} ")?;
-            for file in 'a'..='h' {
-                let pos = Position::new(file, rank).unwrap();
-                if let Some(piece) = self.squares.get(&pos) {
-                    let symbol = match (piece.color, piece.kind) {
-                        (Color::White, PieceType::Pawn) => '♙',
-                        (Color::White, PieceType::Rook) => '♖',
-                        (Color::White, PieceType::Knight) => '♘',
-                        (Color::White, PieceType::Bishop) => '♗',
-                        (Color::White, PieceType::Queen) => '♕',
-                        (Color::White, PieceType::King) => '♔',
-                        (Color::Black, PieceType::Pawn) => '♟',
-                        (Color::Black, PieceType::Rook) => '♜',
-                        (Color::Black, PieceType::Knight) => '♞',
-                        (Color::Black, PieceType::Bishop) => '♝',
-                        (Color::Black, PieceType::Queen) => '♛',
-                        (Color::Black, PieceType::King) => '♚',
-                    };
-                    write!(w, " {symbol} ")?;
-                } else {
-                    write!(w, " . ")?;
-                }
+            for &file in &files {
+                let pos = Position { file, rank };
+                let symbol = match self.squares.get(&pos) {
+                    Some(piece) if opts.unicode => Self::unicode_symbol(*piece),
+                    Some(piece) if piece.color == Color::White => piece.kind.to_char().to_ascii_uppercase(),
+                    Some(piece) => piece.kind.to_char(),
+                    None if pos.is_light() => opts.light_square,
+                    None => opts.dark_square,
+                };
+                write!(w, " {symbol} ")?;
             }
             writeln!(w)?;
         }
-        writeln!(w, "   a  b  c  d  e  f  g  h")?;
-        Ok(())
+        let footer: Vec<String> = files.iter().map(char::to_string).collect();
+        writeln!(w, "   {}", footer.join("  "))
+    }
+
+    /// The Unicode chess symbol for `piece`.
+    fn unicode_symbol(piece: Piece) -> char {
+        match (piece.color, piece.kind) {
+            (Color::White, PieceType::Pawn) => '♙',
+            (Color::White, PieceType::Rook) => '♖',
+            (Color::White, PieceType::Knight) => '♘',
+            (Color::White, PieceType::Bishop) => '♗',
+            (Color::White, PieceType::Queen) => '♕',
+            (Color::White, PieceType::King) => '♔',
+            (Color::Black, PieceType::Pawn) => '♟',
+            (Color::Black, PieceType::Rook) => '♜',
+            (Color::Black, PieceType::Knight) => '♞',
+            (Color::Black, PieceType::Bishop) => '♝',
+            (Color::Black, PieceType::Queen) => '♛',
+            (Color::Black, PieceType::King) => '♚',
+        }
+    }
+
+}
+
+/// Boards are equal when they describe the same position: the same pieces on the same squares,
+/// side to move, castling rights and en passant target.
+///
+/// The move counters, game state and history are deliberately ignored, so boards reached by
+/// different move orders compare equal. This makes boards usable as keys in a `HashSet` for
+/// repetition tracking.
+///
+/// # Examples
+///
+/// ```
+/// use puzzle_engine::chess::*;
+///
+/// let mut a = Board::new();
+/// let mut b = Board::new();
+/// for mv in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+///     a.make_uci_move(mv).unwrap();
+/// }
+/// assert_eq!(a, b);
+/// b.white_can_castle_kingside = false;
+/// assert_ne!(a, b);
+/// ```
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.squares == other.squares
+            && self.turn == other.turn
+            && self.castling_rights() == other.castling_rights()
+            && self.en_passant_target == other.en_passant_target
     }
+}
+
+impl Eq for Board {}
+
+impl std::hash::Hash for Board {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // Hash squares in a fixed order, since the map's iteration order varies
+        for pos in Position::all() {
+            self.squares.get(&pos).hash(state);
+        }
+        self.turn.hash(state);
+        self.castling_rights().hash(state);
+        self.en_passant_target.hash(state);
+    }
+}
 
+/// Formats the board as drawn by [`Board::write_display`].
+///
+/// # Examples
+///
+/// ```
+/// use puzzle_engine::chess::*;
+///
+/// let text = Board::new().to_string();
+/// assert!(text.starts_with("8  ♜"));
+/// ```
+impl std::fmt::Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.write_display(f)
+    }
 }
 
 #[cfg(test)]
@@ -1165,16 +2924,46 @@ mod tests {
     }
 
     #[test]
-    fn test_display_initial_board() {
+    fn test_display_trait_matches_write_display() {
         let board = Board::new();
+        let text = format!("{board}");
         let mut output = String::new();
         board.write_display(&mut output).unwrap();
+        assert_eq!(text, output);
+        assert!(text.contains("a  b  c  d  e  f  g  h"));
+        assert!(text.contains('♔') && text.contains('♚'));
+    }
 
-        // Check critical pieces individually
+    #[test]
+    fn test_write_display_with_options() {
+        let mut board = Board::empty();
+        board.set_piece(Position::new('a', 1).unwrap(), Piece { color: Color::White, kind: PieceType::Rook });
+        let opts = DisplayOptions { light_square: '-', dark_square: '#', unicode: false, orientation: Color::White };
+        let mut output = String::new();
+        board.write_display_with(&mut output, opts).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "8  -  #  -  #  -  #  -  # ");
+        assert_eq!(lines[7], "1  R  -  #  -  #  -  #  - ");
+
+        let flipped = DisplayOptions { orientation: Color::Black, ..opts };
+        output.clear();
+        board.write_display_with(&mut output, flipped).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "1  -  #  -  #  -  #  -  R ");
+        assert_eq!(lines[8], "   h  g  f  e  d  c  b  a");
+    }
 
-        // Black back rank (rank 8)
-        assert!(output.contains("8"), "Rank 8 missing");
-        assert!(output.contains("♜"), "Black rook missing");
+    #[test]
+    fn test_display_initial_board() {
+        let board = Board::new();
+        let mut output = String::new();
+        board.write_display(&mut output).unwrap();
+
+        // Check critical pieces individually
+
+        // Black back rank (rank 8)
+        assert!(output.contains("8"), "Rank 8 missing");
+        assert!(output.contains("♜"), "Black rook missing");
         assert!(output.contains("♞"), "Black knight missing");
         assert!(output.contains("♝"), "Black bishop missing");
         assert!(output.contains("♛"), "Black queen missing");
@@ -1227,7 +3016,7 @@ mod moves_in_direction_tests {
     fn test_moves_blocked_by_ally() {
         let mut board = Board::new();
         let from = Position::new('d', 4).unwrap();
-        board.squares.insert(Position::new('f', 4).unwrap(), Piece { color: Color::White, kind: PieceType::Pawn });
+        board.set_piece(Position::new('f', 4).unwrap(), Piece { color: Color::White, kind: PieceType::Pawn });
 
         let directions = &[(1, 0)]; // East
 
@@ -1245,7 +3034,7 @@ mod moves_in_direction_tests {
     fn test_moves_blocked_by_enemy() {
         let mut board = Board::new();
         let from = Position::new('d', 4).unwrap();
-        board.squares.insert(Position::new('f', 4).unwrap(), Piece { color: Color::Black, kind: PieceType::Pawn });
+        board.set_piece(Position::new('f', 4).unwrap(), Piece { color: Color::Black, kind: PieceType::Pawn });
 
         let directions = &[(1, 0)]; // East
 
@@ -1276,830 +3065,2441 @@ mod moves_in_direction_tests {
     }
 
     #[test]
-    fn test_moves_off_board_edge_case() {
-        let board = Board::new();
-        let from = Position::new('h', 8).unwrap();
-        let directions = &[(1, 0), (0, 1), (1, 1)]; // All directions that should immediately go off board
+    fn test_moves_off_board_edge_case() {
+        let board = Board::new();
+        let from = Position::new('h', 8).unwrap();
+        let directions = &[(1, 0), (0, 1), (1, 1)]; // All directions that should immediately go off board
+
+        let moves = board.moves_in_directions(from, directions, Color::White);
+
+        assert!(moves.is_empty(), "Should not generate any moves from h8 in these directions");
+    }
+
+    #[test]
+    fn test_moves_in_multiple_directions() {
+        let board = Board::new();
+        let from = Position::new('d', 4).unwrap();
+        let directions = &[(1, 0), (0, 1)]; // East and North
+
+        let moves = board.moves_in_directions(from, directions, Color::White);
+
+        // d4 east: e4, f4, g4, h4
+        // d4 north: d5, d6, d7, d8
+        let expected_positions = [
+            ('e', 4), ('f', 4), ('g', 4), ('h', 4),
+            ('d', 5), ('d', 6), ('d', 7),
+        ];
+
+        assert_eq!(moves.len(), expected_positions.len());
+        for expected in expected_positions.iter() {
+            assert!(moves.contains(&Position::new(expected.0, expected.1).unwrap()));
+        }
+    }
+}
+#[cfg(test)]
+mod check_tests {
+    use super::*;
+
+    #[test]
+    fn test_not_in_check_starting_position() {
+        let board = Board::new();
+        assert!(!board.is_in_check(Color::White), "White should not be in check at start.");
+        assert!(!board.is_in_check(Color::Black), "Black should not be in check at start.");
+    }
+
+    #[test]
+    fn test_in_check_simple_rook_attack() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('e', 8, Color::Black, PieceType::Rook),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+
+        assert!(board.is_in_check(Color::White), "White king should be in check from black rook.");
+    }
+
+    #[test]
+    fn test_not_in_check_rook_blocked() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('e', 4, Color::White, PieceType::Pawn), // Blocking pawn
+            ('e', 8, Color::Black, PieceType::Rook),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(!board.is_in_check(Color::White), "White king should not be in check (rook is blocked).");
+    }
+
+    #[test]
+    fn test_in_check_diagonal_bishop_attack() {
+        let pieces = vec![
+            ('c', 1, Color::White, PieceType::King),
+            ('f', 4, Color::Black, PieceType::Bishop),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(board.is_in_check(Color::White), "White king should be in check from bishop on diagonal.");
+    }
+
+    #[test]
+    fn test_in_check_knight_attack() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('d', 3, Color::Black, PieceType::Knight),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(board.is_in_check(Color::White), "White king should be in check from knight.");
+    }
+
+    #[test]
+    fn test_in_check_queen_attack() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('e', 5, Color::Black, PieceType::Queen),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(board.is_in_check(Color::White), "White king should be in check from queen along file.");
+    }
+
+    #[test]
+    fn test_in_check_multiple_threats() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('e', 8, Color::Black, PieceType::Rook),
+            ('a', 1, Color::Black, PieceType::Rook),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(board.is_in_check(Color::White), "White king should be in check from two directions.");
+    }
+
+    #[test]
+    fn test_not_in_check_empty_board() {
+        let board = Board::new();
+        assert!(!board.is_in_check(Color::White), "Empty board should not cause check (no kings).");
+        assert!(!board.is_in_check(Color::Black), "Empty board should not cause check (no kings).");
+    }
+
+    #[test]
+    fn test_in_check_pawn_attack() {
+        let pieces = vec![
+            ('e', 4, Color::White, PieceType::King),
+            ('d', 5, Color::Black, PieceType::Pawn), // Pawn threatens e4
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(board.is_in_check(Color::White), "White king should be in check from black pawn.");
+    }
+
+    #[test]
+    fn test_not_in_check_wrong_color_pawn() {
+        let pieces = vec![
+            ('e', 4, Color::Black, PieceType::King),
+            ('d', 5, Color::White, PieceType::Pawn), // White pawn can't threaten Black king backwards
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(!board.is_in_check(Color::Black), "White pawn should not check black king from above.");
+    }
+
+    #[test]
+    fn test_not_checkmated_at_start() {
+        let board = Board::new();
+        assert!(!board.is_checkmate(Color::White), "White should not be checkmated at starting position.");
+        assert!(!board.is_checkmate(Color::Black), "Black should not be checkmated at starting position.");
+    }
+}
+
+#[cfg(test)]
+mod checkmake_tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_check_but_not_checkmate() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('e', 8, Color::Black, PieceType::Rook), // Black rook attacking, but king can move
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(board.is_in_check(Color::White), "White king should be in check.");
+        assert!(!board.is_checkmate(Color::White), "White king should not be checkmated (can escape).");
+    }
+
+    #[test]
+    fn test_simple_checkmate_by_rook_and_king_blocked() {
+        let pieces = vec![
+            ('a', 1, Color::White, PieceType::King),
+            ('a', 2, Color::Black, PieceType::Rook),
+            ('b', 2, Color::Black, PieceType::Rook),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(board.is_in_check(Color::White), "White king should be in check.");
+        assert!(board.is_checkmate(Color::White), "White king should be checkmated (blocked on all sides).");
+    }
+
+    #[test]
+    fn test_simple_check_by_rook_and_king_can_take_to_escape() {
+        let pieces = vec![
+            ('a', 1, Color::White, PieceType::King),
+            ('a', 2, Color::Black, PieceType::Rook),
+            ('b', 3, Color::Black, PieceType::Rook),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(board.is_in_check(Color::White), "White king should be in check.");
+        assert!(!board.is_checkmate(Color::White), "White king should be checkmated (blocked on all sides).");
+    }
+
+    #[test]
+    fn test_checkmate_by_queen() {
+        let pieces = vec![
+            ('a', 1, Color::White, PieceType::King),
+            ('h', 1, Color::Black, PieceType::Queen),
+            ('b', 3, Color::Black, PieceType::King),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(board.is_in_check(Color::White), "White king should be in check from queen.");
+        assert!(board.is_checkmate(Color::White), "White king should be checkmated (no escape).");
+    }
+
+    #[test]
+    fn test_not_checkmate_king_can_escape() {
+        let pieces = vec![
+            ('a', 1, Color::White, PieceType::King),
+            ('a', 3, Color::Black, PieceType::Queen),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(board.is_in_check(Color::White), "White king should be in check.");
+        assert!(!board.is_checkmate(Color::White), "White king should be able to escape (move).");
+    }
+
+    #[test]
+    fn test_checkmate_fools_mate() {
+        let mut board = Board::new();
+
+        // Simulate Fool's Mate
+        board.try_move(Position::new('f', 2).unwrap(), Position::new('f', 3).unwrap(), None).unwrap();
+        board.try_move(Position::new('e', 7).unwrap(), Position::new('e', 5).unwrap(), None).unwrap();
+        board.try_move(Position::new('g', 2).unwrap(), Position::new('g', 4).unwrap(), None).unwrap();
+        board.try_move(Position::new('d', 8).unwrap(), Position::new('h', 4).unwrap(), None).unwrap();
+
+        assert!(board.is_in_check(Color::White), "White should be in check.");
+        assert!(board.is_checkmate(Color::White), "White should be checkmated in Fool's Mate.");
+    }
+
+    #[test]
+    fn test_not_checkmate_not_in_check() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('a', 8, Color::Black, PieceType::Rook),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        assert!(!board.is_in_check(Color::White), "White king should not be in check.");
+        assert!(!board.is_checkmate(Color::White), "White king should not be checkmated if not in check.");
+    }
+
+    #[test]
+    fn test_checkmate_edge_case_empty_board() {
+        let board = Board::new();
+
+        // No kings on the board technically — behavior undefined, but should NOT panic
+        assert!(!board.is_checkmate(Color::White), "Empty board should not panic or cause checkmate.");
+        assert!(!board.is_checkmate(Color::Black), "Empty board should not panic or cause checkmate.");
+    }
+
+    #[test]
+    fn test_checkmate_smothered_mate() {
+        let pieces = vec![
+            ('h', 8, Color::Black, PieceType::King),
+            ('h', 7, Color::Black, PieceType::Pawn),
+            ('g', 7, Color::Black, PieceType::King),
+            ('g', 8, Color::Black, PieceType::Rook),
+            ('f', 7, Color::White, PieceType::Knight),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        // No kings on the board technically — behavior undefined, but should NOT panic
+        assert!(!board.is_checkmate(Color::White), "Empty board should not panic or cause checkmate.");
+        assert!(!board.is_checkmate(Color::Black), "Empty board should not panic or cause checkmate.");
+    }
+}
+#[cfg(test)]
+mod try_move_tests {
+    use super::*;
+
+    #[test]
+    fn test_try_move_successful_normal_move() {
+        let mut board = Board::new();
+        let result = board.try_move(Position::new('e', 2).unwrap(), Position::new('e', 4).unwrap(), None);
+        assert!(result.is_ok(), "Expected pawn move from e2 to e4 to succeed.");
+        assert_eq!(board.turn, Color::Black, "Turn should switch to Black after move.");
+        assert_eq!(board.game_state, GameState::Ongoing, "Game should continue after normal move.");
+    }
+
+    #[test]
+    fn test_try_move_no_piece_at_start() {
+        let mut board = Board::new();
+        let result = board.try_move(Position::new('e', 3).unwrap(), Position::new('e', 4).unwrap(), None);
+        assert!(result.is_err(), "Expected error when no piece at starting position.");
+    }
+
+    #[test]
+    fn test_try_move_illegal_move_attempt() {
+        let mut board = Board::new();
+        let result = board.try_move(Position::new('e', 2).unwrap(), Position::new('e', 5).unwrap(), None); // Illegal: pawn can't jump to e5 directly
+        assert!(result.is_err(), "Expected illegal move error for pawn jumping 3 spaces.");
+    }
+
+    #[test]
+    fn test_try_move_not_players_turn() {
+        let mut board = Board::new();
+        board.turn = Color::Black;
+        let result = board.try_move(Position::new('e', 2).unwrap(), Position::new('e', 4).unwrap(), None);
+        assert!(result.is_err(), "Expected error when moving out of turn.");
+    }
+
+    #[test]
+    fn test_try_move_into_check_disallowed() {
+        let pieces = vec![
+            ('a', 1, Color::White, PieceType::King),
+            ('b', 2, Color::Black, PieceType::Rook),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        let result = board.try_move(Position::new('a', 1).unwrap(), Position::new('b', 1).unwrap(), None);
+        assert!(result.is_err(), "Expected error when moving into check.");
+    }
+
+    #[test]
+    fn test_try_move_checkmate_after_move() {
+        let pieces = vec![
+            ('a', 1, Color::White, PieceType::King),
+            ('d', 8, Color::Black, PieceType::Queen),
+            ('b', 3, Color::Black, PieceType::King),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        board.turn = Color::Black;
+        let result = board.try_move(Position::new('d', 8).unwrap(), Position::new('d', 1).unwrap(), None);
+        assert!(result.is_ok(), "Expected queen move to e5 to succeed.");
+        
+        match board.game_state {
+            GameState::Checkmate(Color::White) => {},
+            other => panic!("Expected White to be checkmated after move, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_try_move_no_checkmate_if_king_can_escape() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('f', 3, Color::Black, PieceType::Queen),
+        ];
+        let turn = Color::Black;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        let result = board.try_move(Position::new('f', 3).unwrap(), Position::new('f', 2).unwrap(), None);
+        assert!(result.is_ok(), "Expected move to succeed.");
+        assert_eq!(board.game_state, GameState::Ongoing, "Game should remain ongoing (king can escape).");
+    }
+
+    #[test]
+    fn test_try_move_stalemate_placeholder() {
+        let board = Board::new();
+
+        assert_eq!(board.game_state, GameState::Ongoing, "Empty board should be ongoing (no stalemate yet).");
+    }
+
+    #[test]
+    fn test_try_move_fools_mate_checkmate() {
+        let mut board = Board::new();
+
+        // Fool's Mate (quickest checkmate in chess)
+        board.try_move(Position::new('f', 2).unwrap(), Position::new('f', 3).unwrap(), None).unwrap();
+        board.try_move(Position::new('e', 7).unwrap(), Position::new('e', 5).unwrap(), None).unwrap();
+        board.try_move(Position::new('g', 2).unwrap(), Position::new('g', 4).unwrap(), None).unwrap();
+        board.try_move(Position::new('d', 8).unwrap(), Position::new('h', 4).unwrap(), None).unwrap();
+
+        match board.game_state {
+            GameState::Checkmate(Color::White) => {},
+            other => panic!("Expected White to be checkmated in Fool's Mate, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_try_move_rejected_after_checkmate() {
+        let mut board = Board::new();
+        board.try_move(Position::new('f', 2).unwrap(), Position::new('f', 3).unwrap(), None).unwrap();
+        board.try_move(Position::new('e', 7).unwrap(), Position::new('e', 5).unwrap(), None).unwrap();
+        board.try_move(Position::new('g', 2).unwrap(), Position::new('g', 4).unwrap(), None).unwrap();
+        board.try_move(Position::new('d', 8).unwrap(), Position::new('h', 4).unwrap(), None).unwrap();
+
+        let squares = board.squares.clone();
+        let result = board.try_move(Position::new('a', 2).unwrap(), Position::new('a', 3).unwrap(), None);
+        assert_eq!(result, Err("Game is over".to_string()));
+        assert_eq!(board.squares, squares, "Board must be unchanged.");
+        assert_eq!(board.turn, Color::White);
+    }
+
+    #[test]
+    fn test_try_move_rejected_after_draw_or_stalemate() {
+        for state in [GameState::Stalemate, GameState::Draw(DrawReason::Agreement)] {
+            let mut board = Board::new();
+            board.game_state = state;
+            assert!(board.try_move(Position::new('e', 2).unwrap(), Position::new('e', 4).unwrap(), None).is_err());
+        }
+    }
+}
+#[cfg(test)]
+mod promotion_tests {
+    use super::*;
+    #[test]
+    fn test_pawn_promotion_to_queen() {
+        let mut board = Board::new();
+        board.clear();
+
+        // White pawn at 7th rank
+        board.set_piece(Position::new('a', 7).unwrap(), Piece { color: Color::White, kind: PieceType::Pawn });
+        board.turn = Color::White;
+
+        // Move to 8th rank with promotion
+        let from = Position::new('a', 7).unwrap();
+        let to = Position::new('a', 8).unwrap();
+        board.try_move(from, to, Some(PieceType::Queen)).unwrap();
+
+        let piece = board.squares.get(&to).unwrap();
+        assert_eq!(piece.kind, PieceType::Queen);
+        assert_eq!(piece.color, Color::White);
+    }
+
+    #[test]
+    fn test_pawn_promotion_checkmate() {
+        let pieces = vec![
+            ('h', 8, Color::Black, PieceType::Rook),
+            ('h', 7, Color::Black, PieceType::King),
+            ('g', 7, Color::Black, PieceType::Pawn),
+            ('g', 8, Color::Black, PieceType::Bishop),
+            ('g', 8, Color::Black, PieceType::Bishop),
+            ('h', 6, Color::Black, PieceType::Rook),
+            ('f', 7, Color::White, PieceType::Pawn),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        let from = {Position { file: ('f'), rank: (7) }};
+        let to = {Position { file: ('f'), rank: (8) }};
+        board.try_move(from, to, Some(PieceType::Knight)).unwrap();
+
+        assert_eq!(GameState::Checkmate(Color::Black), board.game_state);
+    }
+
+    #[test]
+    fn test_promotion_to_king_rejected_without_changing_board() {
+        let pieces = vec![
+            ('a', 7, Color::White, PieceType::Pawn),
+            ('e', 1, Color::White, PieceType::King),
+            ('e', 8, Color::Black, PieceType::King),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        let before = board.clone();
+
+        let result = board.try_move(Position::new('a', 7).unwrap(), Position::new('a', 8).unwrap(), Some(PieceType::King));
+
+        assert_eq!(result, Err("Invalid promotion piece.".to_string()));
+        assert_eq!(board.squares, before.squares);
+        assert_eq!(board.turn, Color::White);
+        assert_eq!(board.halfmove_clock, before.halfmove_clock);
+    }
+}
+#[cfg(test)]
+mod castle_tests {
+    use super::*;
+
+    #[test]
+    fn test_white_kingside_castling() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('h', 1, Color::White, PieceType::Rook),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        board.set_castling_rights(true, true, true, true);
+
+        assert!(board.try_move(Position::new('e', 1).unwrap(), Position::new('g', 1).unwrap(), None).is_ok());
+
+        assert_eq!(board.squares.get(&Position::new('g', 1).unwrap()).unwrap().kind, PieceType::King, "King not at g1");
+        assert_eq!(board.squares.get(&Position::new('f', 1).unwrap()).unwrap().kind, PieceType::Rook, "Rook not at f1");
+    }
+    #[test]
+    fn test_white_kingside_castling_with_piece_blocking() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('h', 1, Color::White, PieceType::Rook),
+            ('g', 1, Color::White, PieceType::Queen),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        board.set_castling_rights(true, true, true, true);
+
+        assert!(board.try_move(Position::new('e', 1).unwrap(), Position::new('g', 1).unwrap(), None).is_err());
+    }
+
+    #[test]
+    fn test_white_kingside_castling_with_check_blocking() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('h', 1, Color::White, PieceType::Rook),
+            ('g', 5, Color::Black, PieceType::Queen),
+        ];
+        let turn = Color::White;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        board.set_castling_rights(true, true, true, true);
+
+        assert!(board.try_move(Position::new('e', 1).unwrap(), Position::new('g', 1).unwrap(), None).is_err());
+    }
+
+    #[test]
+    fn test_black_queenside_castling() {
+        let pieces = vec![
+            ('e', 8, Color::Black, PieceType::King),
+            ('a', 8, Color::Black, PieceType::Rook),
+        ];
+        let turn = Color::Black;
+        let game_state = GameState::Ongoing;
+        let mut board = Board::new();
+        board.initialize_custom(pieces, turn, game_state);
+        board.set_castling_rights(true, true, true, true);
+
+        assert!(board.try_move(Position::new('e', 8).unwrap(), Position::new('c', 8).unwrap(), None).is_ok());
+
+        assert_eq!(board.squares.get(&Position::new('c', 8).unwrap()).unwrap().kind, PieceType::King);
+        assert_eq!(board.squares.get(&Position::new('d', 8).unwrap()).unwrap().kind, PieceType::Rook);
+    }
+
+    #[test]
+    fn test_castling_switches_turn_once() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('h', 1, Color::White, PieceType::Rook),
+            ('e', 8, Color::Black, PieceType::King),
+            ('a', 7, Color::Black, PieceType::Pawn),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.set_castling_rights(true, true, true, true);
+
+        board.try_move(Position::new('e', 1).unwrap(), Position::new('g', 1).unwrap(), None).unwrap();
+        assert_eq!(board.turn, Color::Black);
+        assert_eq!(board.halfmove_clock, 1);
+        assert!(board.try_move(Position::new('a', 7).unwrap(), Position::new('a', 6).unwrap(), None).is_ok());
+    }
+
+    #[test]
+    fn test_castling_delivers_checkmate() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('h', 1, Color::White, PieceType::Rook),
+            ('f', 8, Color::Black, PieceType::King),
+            ('e', 8, Color::Black, PieceType::Rook),
+            ('g', 8, Color::Black, PieceType::Rook),
+            ('e', 7, Color::Black, PieceType::Pawn),
+            ('g', 7, Color::Black, PieceType::Pawn),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.set_castling_rights(true, true, true, true);
+
+        board.try_move(Position::new('e', 1).unwrap(), Position::new('g', 1).unwrap(), None).unwrap();
+
+        assert_eq!(board.game_state, GameState::Checkmate(Color::Black));
+    }
+
+    #[test]
+    fn test_custom_position_has_no_castling_rights_until_granted() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('a', 1, Color::White, PieceType::Rook),
+            ('h', 1, Color::White, PieceType::Rook),
+            ('e', 8, Color::Black, PieceType::King),
+            ('a', 8, Color::Black, PieceType::Rook),
+            ('h', 8, Color::Black, PieceType::Rook),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        let e1 = Position::new('e', 1).unwrap();
+        let e8 = Position::new('e', 8).unwrap();
+        assert!(!board.is_legal_move(e1, Position::new('g', 1).unwrap(), None));
+        assert!(!board.is_legal_move(e1, Position::new('c', 1).unwrap(), None));
+
+        board.set_castling_rights(false, true, true, false);
+        assert!(!board.is_legal_move(e1, Position::new('g', 1).unwrap(), None));
+        assert!(board.is_legal_move(e1, Position::new('c', 1).unwrap(), None));
+        board.try_move(e1, Position::new('c', 1).unwrap(), None).unwrap();
+        assert!(board.is_legal_move(e8, Position::new('g', 8).unwrap(), None));
+        assert!(!board.is_legal_move(e8, Position::new('c', 8).unwrap(), None));
+    }
+}
+
+#[cfg(test)]
+mod get_legal_moves_tests {
+    use super::*;
+
+    #[test]
+    fn test_mobility_symmetric_at_start_and_grows_when_opened() {
+        let mut board = Board::new();
+        assert_eq!(board.mobility(Color::White), 20);
+        assert_eq!(board.mobility(Color::White), board.mobility(Color::Black));
+
+        board.make_uci_move("e2e4").unwrap();
+        board.make_uci_move("e7e5").unwrap();
+        assert!(board.mobility(Color::White) > 20, "Opening the e-pawn frees the bishop, queen and king.");
+    }
+
+    #[test]
+    fn test_pawn_initial_double_move() {
+        let pieces = vec![
+            ('e', 2, Color::White, PieceType::Pawn),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        let moves = board.get_legal_moves(Position::new('e', 2).unwrap());
+        assert!(moves.contains(&Position::new('e', 3).unwrap()), "Pawn should move forward 1 square.");
+        assert!(moves.contains(&Position::new('e', 4).unwrap()), "Pawn should move forward 2 squares on first move.");
+    }
+
+    #[test]
+    fn test_pawn_blocked_forward() {
+        let pieces = vec![
+            ('e', 2, Color::White, PieceType::Pawn),
+            ('e', 3, Color::Black, PieceType::Pawn),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        let moves = board.get_legal_moves(Position::new('e', 2).unwrap());
+        assert!(moves.is_empty(), "Pawn should not move if blocked.");
+    }
+
+    #[test]
+    fn test_pawn_capture_diagonal() {
+        let pieces = vec![
+            ('d', 4, Color::White, PieceType::Pawn),
+            ('c', 5, Color::Black, PieceType::Pawn),
+            ('e', 5, Color::Black, PieceType::Pawn),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        let moves = board.get_legal_moves(Position::new('d', 4).unwrap());
+        assert!(moves.contains(&Position::new('c', 5).unwrap()), "Pawn should capture diagonally left.");
+        assert!(moves.contains(&Position::new('e', 5).unwrap()), "Pawn should capture diagonally right.");
+    }
+
+    #[test]
+    fn test_knight_movement_open_board() {
+        let pieces = vec![
+            ('d', 4, Color::White, PieceType::Knight),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        let moves = board.get_legal_moves(Position::new('d', 4).unwrap());
+        let expected_positions = [
+            ('c', 6), ('e', 6),
+            ('b', 5), ('f', 5),
+            ('b', 3), ('f', 3),
+            ('c', 2), ('e', 2),
+        ];
+        for (file, rank) in expected_positions.iter() {
+            assert!(moves.contains(&Position::new(*file, *rank).unwrap()), "Knight move to {}{} missing.", file, rank);
+        }
+    }
+
+    #[test]
+    fn test_rook_movement_clear() {
+        let pieces = vec![
+            ('d', 4, Color::White, PieceType::Rook),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        let moves = board.get_legal_moves(Position::new('d', 4).unwrap());
+        let expected_positions = [
+            ('d', 5), ('d', 6), ('d', 7), ('d', 8),
+            ('d', 3), ('d', 2), ('d', 1),
+            ('e', 4), ('f', 4), ('g', 4), ('h', 4),
+            ('c', 4), ('b', 4), ('a', 4),
+        ];
+        for (file, rank) in expected_positions.iter() {
+            assert!(moves.contains(&Position::new(*file, *rank).unwrap()), "Rook move to {}{} missing.", file, rank);
+        }
+    }
+
+    #[test]
+    fn test_bishop_movement_clear() {
+        let pieces = vec![
+            ('d', 4, Color::White, PieceType::Bishop),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        let moves = board.get_legal_moves(Position::new('d', 4).unwrap());
+        let expected_positions = [
+            ('e', 5), ('f', 6), ('g', 7), ('h', 8),
+            ('c', 5), ('b', 6), ('a', 7),
+            ('e', 3), ('f', 2), ('g', 1),
+            ('c', 3), ('b', 2), ('a', 1),
+        ];
+        for (file, rank) in expected_positions.iter() {
+            assert!(moves.contains(&Position::new(*file, *rank).unwrap()), "Bishop move to {}{} missing.", file, rank);
+        }
+    }
+
+    #[test]
+    fn test_queen_movement_clear() {
+        let pieces = vec![
+            ('d', 4, Color::White, PieceType::Queen),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        let moves = board.get_legal_moves(Position::new('d', 4).unwrap());
+
+        assert!(moves.contains(&Position::new('d', 5).unwrap()), "Queen vertical move missing.");
+        assert!(moves.contains(&Position::new('e', 5).unwrap()), "Queen diagonal move missing.");
+        assert!(moves.contains(&Position::new('h', 4).unwrap()), "Queen horizontal move missing.");
+    }
+
+    #[test]
+    fn test_king_movement_clear() {
+        let pieces = vec![
+            ('e', 4, Color::White, PieceType::King),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        let moves = board.get_legal_moves(Position::new('e', 4).unwrap());
+        let expected_positions = [
+            ('d', 4), ('f', 4),
+            ('e', 5), ('e', 3),
+            ('d', 5), ('f', 5),
+            ('d', 3), ('f', 3),
+        ];
+        for (file, rank) in expected_positions.iter() {
+            assert!(moves.contains(&Position::new(*file, *rank).unwrap()), "King move to {}{} missing.", file, rank);
+        }
+    }
+
+    #[test]
+    fn test_king_castling_moves_allowed() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('h', 1, Color::White, PieceType::Rook),
+            ('a', 1, Color::White, PieceType::Rook),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.set_castling_rights(true, true, true, true);
+
+        let moves = board.get_legal_moves(Position::new('e', 1).unwrap());
+        assert!(moves.contains(&Position::new('g', 1).unwrap()), "Kingside castling move should be allowed.");
+        assert!(moves.contains(&Position::new('c', 1).unwrap()), "Queenside castling move should be allowed.");
+    }
+
+    #[test]
+    fn test_king_castling_blocked_path() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('h', 1, Color::White, PieceType::Rook),
+            ('f', 1, Color::White, PieceType::Knight), // Blocking kingside
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.set_castling_rights(true, true, true, true);
+
+        let moves = board.get_legal_moves(Position::new('e', 1).unwrap());
+        assert!(!moves.contains(&Position::new('g', 1).unwrap()), "Kingside castling should not be allowed if path is blocked.");
+    }
+
+    #[test]
+    fn test_get_legal_moves_empty_square() {
+        let pieces = vec![];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        let moves = board.get_legal_moves(Position::new('e', 4).unwrap());
+        assert!(moves.is_empty(), "No moves should exist for empty square.");
+    }
+}
+
+#[cfg(test)]
+mod en_passant_tests {
+    use super::*;
+
+    #[test]
+    fn test_en_passant_available() {
+        let pieces = vec![
+            ('e', 5, Color::White, PieceType::Pawn),
+            ('d', 7, Color::Black, PieceType::Pawn),
+            ('e', 8, Color::Black, PieceType::King),
+            ('a', 8, Color::Black, PieceType::Rook),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::Black, GameState::Ongoing);
+
+        // Black plays d7-d5
+        board.try_move(Position::new('d', 7).unwrap(), Position::new('d', 5).unwrap(), None).unwrap();
+
+        // Now white can en passant capture at d6
+        let moves = board.get_legal_moves(Position::new('e', 5).unwrap());
+        assert!(moves.contains(&Position::new('d', 6).unwrap()), "En passant should be available");
+    }
+
+    #[test]
+    fn test_en_passant_capture_execution() {
+        let pieces = vec![
+            ('e', 5, Color::White, PieceType::Pawn),
+            ('d', 7, Color::Black, PieceType::Pawn),
+            ('e', 8, Color::Black, PieceType::King),
+            ('a', 8, Color::Black, PieceType::Rook),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::Black, GameState::Ongoing);
+
+        // Black plays d7-d5
+        board.try_move(Position::new('d', 7).unwrap(), Position::new('d', 5).unwrap(), None).unwrap();
+
+        // White plays e5xd6 en passant
+        board.try_move(Position::new('e', 5).unwrap(), Position::new('d', 6).unwrap(), None).unwrap();
+
+        assert!(board.squares.get(&Position::new('d', 5).unwrap()).is_none(), "Captured pawn should be gone after en passant.");
+        assert_eq!(board.squares.get(&Position::new('d', 6).unwrap()).unwrap().kind, PieceType::Pawn);
+    }
+
+    #[test]
+    fn test_en_passant_target_distinguishes_positions() {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+        let hash = |board: &Board| {
+            let mut hasher = DefaultHasher::new();
+            board.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let mut board = Board::new();
+        board.make_uci_move("e2e4").unwrap();
+        assert_eq!(board.en_passant_target, Position::new('e', 3), "The target is the square the pawn passed.");
+        assert!(board.position_key().ends_with("e3"));
+        let with_target = board.clone();
+
+        // The same pieces with Black to move again, but no longer able to capture en passant
+        for mv in ["g8f6", "g1f3", "f6g8", "f3g1"] {
+            board.make_uci_move(mv).unwrap();
+            assert_eq!(board.en_passant_target, None);
+        }
+        assert_eq!(board.squares, with_target.squares);
+        assert_eq!(board.turn, with_target.turn);
+        assert_ne!(board.position_key(), with_target.position_key());
+        assert_ne!(board, with_target);
+        assert_ne!(hash(&board), hash(&with_target));
+    }
+}
+
+#[cfg(test)]
+mod stalemate_tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_stalemate_position() {
+        let pieces = vec![
+            ('h', 1, Color::White, PieceType::King),
+            ('f', 2, Color::Black, PieceType::Queen),
+            ('g', 3, Color::Black, PieceType::King),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        assert!(board.is_stalemate(Color::White), "White should be stalemated in this position.");
+    }
+
+    #[test]
+    fn test_side_to_move_helpers() {
+        let pieces = vec![
+            ('h', 1, Color::White, PieceType::King),
+            ('f', 2, Color::Black, PieceType::Queen),
+            ('g', 3, Color::Black, PieceType::King),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        assert!(board.side_to_move_stalemated());
+        assert!(!board.side_to_move_checkmated());
+        board.turn = Color::Black;
+        assert!(!board.side_to_move_stalemated());
+
+        let mut board = Board::new();
+        for mv in ["f2f3", "e7e5", "g2g4", "d8h4"] {
+            board.make_uci_move(mv).unwrap();
+        }
+        assert_eq!(board.turn, Color::White);
+        assert!(board.side_to_move_checkmated());
+        assert!(!board.side_to_move_stalemated());
+    }
+
+    #[test]
+    fn test_not_stalemate_king_can_move() {
+        let pieces = vec![
+            ('h', 1, Color::White, PieceType::King),
+            ('f', 1, Color::Black, PieceType::Queen),
+            ('g', 2, Color::Black, PieceType::King),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        assert!(!board.is_stalemate(Color::White), "White should not be stalemated (king has legal moves).");
+    }
+
+    #[test]
+    fn test_stalemate_with_multiple_pieces_on_board() {
+        let pieces = vec![
+            ('a', 1, Color::White, PieceType::King),
+            ('c', 2, Color::Black, PieceType::Queen),
+            ('b', 4, Color::Black, PieceType::Bishop),
+            ('b', 3, Color::White, PieceType::Pawn),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        assert!(board.is_stalemate(Color::White), "White should be stalemated even with other pieces on board.");
+    }
+
+    #[test]
+    fn test_not_stalemate_in_check() {
+        let pieces = vec![
+            ('a', 1, Color::White, PieceType::King),
+            ('c', 3, Color::Black, PieceType::Queen),
+            ('b', 4, Color::Black, PieceType::Bishop),
+            ('b', 3, Color::White, PieceType::Pawn),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        assert!(!board.is_stalemate(Color::White), "White is in check, so not stalemate.");
+    }
+
+    #[test]
+    fn test_no_legal_moves_but_in_checkmate_not_stalemate() {
+        let pieces = vec![
+            ('a', 1, Color::White, PieceType::King),
+            ('b', 2, Color::Black, PieceType::Queen),
+            ('c', 3, Color::Black, PieceType::Bishop),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        assert!(!board.is_stalemate(Color::White), "White is in checkmate, not stalemate.");
+        assert!(board.is_checkmate(Color::White), "White is in checkmate, not stalemate.");
+    }
+
+    #[test]
+    fn test_black_stalemated_simple() {
+        let pieces = vec![
+            ('a', 8, Color::Black, PieceType::King),
+            ('c', 7, Color::White, PieceType::Queen),
+            ('b', 6, Color::White, PieceType::King),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::Black, GameState::Ongoing);
+
+        assert!(board.is_stalemate(Color::Black), "Black should be stalemated.");
+    }
+}
+
+#[cfg(test)]
+mod draw_tests {
+    use super::*;
+
+    #[test]
+    fn test_stalemate_sets_draw_reason() {
+        let pieces = vec![
+            ('b', 6, Color::White, PieceType::King),
+            ('d', 7, Color::White, PieceType::Queen),
+            ('a', 8, Color::Black, PieceType::King),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        board.try_move(Position::new('d', 7).unwrap(), Position::new('c', 7).unwrap(), None).unwrap();
+
+        assert_eq!(board.game_state, GameState::Draw(DrawReason::Stalemate));
+    }
+
+    #[test]
+    fn test_fifty_move_rule_sets_draw_reason() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('a', 1, Color::White, PieceType::Rook),
+            ('e', 8, Color::Black, PieceType::King),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.halfmove_clock = 99;
+
+        board.try_move(Position::new('a', 1).unwrap(), Position::new('a', 2).unwrap(), None).unwrap();
+
+        assert_eq!(board.halfmove_clock, 100);
+        assert_eq!(board.game_state, GameState::Draw(DrawReason::FiftyMove));
+    }
+
+    #[test]
+    fn test_fullmove_number_counts_black_moves() {
+        let mut board = Board::new();
+        assert_eq!(board.fullmove_number, 1);
+        board.make_uci_move("e2e4").unwrap();
+        assert_eq!(board.fullmove_number, 1);
+        board.make_uci_move("e7e5").unwrap();
+        assert_eq!(board.fullmove_number, 2);
+        board.make_uci_move("g1f3").unwrap();
+        assert_eq!(board.fullmove_number, 2);
+        board.reset();
+        assert_eq!(board.fullmove_number, 1);
+    }
+
+    #[test]
+    fn test_claimable_policy_waits_for_claim() {
+        let mut board = Board::new();
+        board.draw_policy = DrawPolicy::Claimable;
+        for mv in ["g1f3", "g8f6", "f3g1", "f6g8", "g1f3", "g8f6", "f3g1", "f6g8"] {
+            board.make_uci_move(mv).unwrap();
+        }
+        assert!(board.is_threefold_repetition());
+        assert_eq!(board.game_state, GameState::Ongoing);
+        assert!(board.can_claim_draw());
+
+        // Play continues until the claim is made
+        board.make_uci_move("e2e4").unwrap();
+        assert!(!board.can_claim_draw());
+        assert_eq!(board.claim_draw(), Err("No draw can be claimed.".to_string()));
+        board.make_uci_move("e7e5").unwrap();
+        board.halfmove_clock = 99;
+        board.make_uci_move("g1f3").unwrap();
+        assert_eq!(board.game_state, GameState::Ongoing);
+        board.claim_draw().unwrap();
+        assert_eq!(board.game_state, GameState::Draw(DrawReason::FiftyMove));
+        assert!(!board.can_claim_draw());
+    }
+
+    #[test]
+    fn test_automatic_policy_draws_repetition() {
+        let mut board = Board::new();
+        for mv in ["g1f3", "g8f6", "f3g1", "f6g8", "g1f3", "g8f6", "f3g1", "f6g8"] {
+            board.make_uci_move(mv).unwrap();
+        }
+        assert_eq!(board.game_state, GameState::Draw(DrawReason::ThreefoldRepetition));
+    }
+
+    #[test]
+    fn test_pawn_move_resets_halfmove_clock() {
+        let mut board = Board::new();
+        board.try_move(Position::new('g', 1).unwrap(), Position::new('f', 3).unwrap(), None).unwrap();
+        assert_eq!(board.halfmove_clock, 1);
+        board.try_move(Position::new('e', 7).unwrap(), Position::new('e', 5).unwrap(), None).unwrap();
+        assert_eq!(board.halfmove_clock, 0);
+    }
+
+    #[test]
+    fn test_threefold_repetition_sets_draw_reason() {
+        let mut board = Board::new();
+        let shuffle = [('g', 1, 'f', 3), ('g', 8, 'f', 6), ('f', 3, 'g', 1), ('f', 6, 'g', 8)];
+
+        for (from_file, from_rank, to_file, to_rank) in shuffle {
+            board.try_move(Position::new(from_file, from_rank).unwrap(), Position::new(to_file, to_rank).unwrap(), None).unwrap();
+        }
+        assert_eq!(board.game_state, GameState::Ongoing, "Second occurrence is not yet a draw.");
+
+        for (from_file, from_rank, to_file, to_rank) in shuffle {
+            board.try_move(Position::new(from_file, from_rank).unwrap(), Position::new(to_file, to_rank).unwrap(), None).unwrap();
+        }
+        assert!(board.is_threefold_repetition());
+        assert_eq!(board.game_state, GameState::Draw(DrawReason::ThreefoldRepetition));
+    }
+
+    #[test]
+    fn test_insufficient_material_sets_draw_reason() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('b', 1, Color::White, PieceType::Knight),
+            ('e', 8, Color::Black, PieceType::King),
+            ('e', 2, Color::Black, PieceType::Rook),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        board.try_move(Position::new('e', 1).unwrap(), Position::new('e', 2).unwrap(), None).unwrap();
+
+        assert_eq!(board.game_state, GameState::Draw(DrawReason::InsufficientMaterial));
+    }
+
+    #[test]
+    fn test_same_colored_bishops_are_insufficient() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('c', 1, Color::White, PieceType::Bishop),
+            ('e', 8, Color::Black, PieceType::King),
+            ('c', 8, Color::Black, PieceType::Bishop),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        assert!(!board.is_insufficient_material(), "c1 and c8 are on different colors.");
+
+        board.remove_piece(Position::new('c', 8).unwrap());
+        board.set_piece(Position::new('f', 8).unwrap(), Piece { color: Color::Black, kind: PieceType::Bishop });
+        assert!(board.is_insufficient_material(), "c1 and f8 are both dark squares.");
+    }
+
+    #[test]
+    fn test_game_result_reports_draws() {
+        let mut board = Board::new();
+        assert_eq!(board.game_result(), None);
+        board.game_state = GameState::Stalemate;
+        assert_eq!(board.game_result(), Some(Outcome::Draw));
+        board.game_state = GameState::Draw(DrawReason::FiftyMove);
+        assert_eq!(board.game_result(), Some(Outcome::Draw));
+    }
+
+    #[test]
+    fn test_game_result_after_stalemating_move() {
+        let pieces = vec![
+            ('b', 6, Color::White, PieceType::King),
+            ('d', 7, Color::White, PieceType::Queen),
+            ('a', 8, Color::Black, PieceType::King),
+        ];
+        let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.try_move(Position::new('d', 7).unwrap(), Position::new('c', 7).unwrap(), None).unwrap();
+        assert_eq!(board.game_result(), Some(Outcome::Draw));
+    }
+
+    #[test]
+    fn test_game_result_checkmate_winner_is_opponent() {
+        let mut board = Board::new();
+        board.make_uci_move("f2f3").unwrap();
+        board.make_uci_move("e7e5").unwrap();
+        board.make_uci_move("g2g4").unwrap();
+        board.make_uci_move("d8h4").unwrap();
+        assert_eq!(board.game_state, GameState::Checkmate(Color::White));
+        assert_eq!(board.game_result(), Some(Outcome::BlackWins));
+    }
+
+    #[test]
+    fn test_agreement_sets_draw_reason() {
+        let mut board = Board::new();
+        board.agree_draw();
+        assert_eq!(board.game_state, GameState::Draw(DrawReason::Agreement));
+    }
+}
+
+#[cfg(test)]
+mod game_state_query_tests {
+    use super::*;
+
+    fn with_state(state: GameState) -> Board {
+        let mut board = Board::new();
+        board.game_state = state;
+        board
+    }
+
+    #[test]
+    fn test_ongoing() {
+        let board = with_state(GameState::Ongoing);
+        assert!(!board.is_game_over());
+        assert_eq!(board.winner(), None);
+        assert!(!board.is_draw());
+    }
+
+    #[test]
+    fn test_checkmate() {
+        let board = with_state(GameState::Checkmate(Color::Black));
+        assert!(board.is_game_over());
+        assert_eq!(board.winner(), Some(Color::White));
+        assert!(!board.is_draw());
+    }
+
+    #[test]
+    fn test_stalemate() {
+        for state in [GameState::Stalemate, GameState::Draw(DrawReason::Stalemate)] {
+            let board = with_state(state);
+            assert!(board.is_game_over());
+            assert_eq!(board.winner(), None);
+            assert!(board.is_draw());
+        }
+    }
+
+    #[test]
+    fn test_draw() {
+        let board = with_state(GameState::Draw(DrawReason::ThreefoldRepetition));
+        assert!(board.is_game_over());
+        assert_eq!(board.winner(), None);
+        assert!(board.is_draw());
+    }
+}
+#[cfg(test)]
+mod empty_board_tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_board_has_no_pieces_or_castling_rights() {
+        let board = Board::empty();
+        assert!(board.squares.is_empty());
+        assert_eq!(board.turn, Color::White);
+        assert!(!board.white_can_castle_kingside);
+        assert!(!board.white_can_castle_queenside);
+        assert!(!board.black_can_castle_kingside);
+        assert!(!board.black_can_castle_queenside);
+    }
+
+    #[test]
+    fn test_clear_keeps_turn_and_allows_setup() {
+        let mut board = Board::new();
+        board.make_uci_move("e2e4").unwrap();
+        board.clear();
+        assert!(board.squares.is_empty());
+        assert_eq!(board.turn, Color::Black);
+        assert_eq!(board.en_passant_target, None);
+        assert!(!board.is_in_check(Color::White));
+
+        board.set_piece(Position::new('e', 8).unwrap(), Piece { color: Color::Black, kind: PieceType::King });
+        board.set_piece(Position::new('a', 1).unwrap(), Piece { color: Color::White, kind: PieceType::King });
+        assert!(board.try_move(Position::new('e', 8).unwrap(), Position::new('e', 7).unwrap(), None).is_ok());
+    }
+}
+#[cfg(test)]
+mod set_piece_tests {
+    use super::*;
+
+    #[test]
+    fn test_set_king_updates_cached_position() {
+        let mut board = Board::empty();
+        let king = Piece { color: Color::Black, kind: PieceType::King };
+        board.set_piece(Position::new('c', 6).unwrap(), king);
+        assert_eq!(board.black_king, Some(Position::new('c', 6).unwrap()));
+
+        board.set_piece(Position::new('a', 1).unwrap(), Piece { color: Color::White, kind: PieceType::Rook });
+        board.set_piece(Position::new('c', 1).unwrap(), Piece { color: Color::White, kind: PieceType::Rook });
+        assert!(board.is_in_check(Color::Black));
+
+        assert_eq!(board.remove_piece(Position::new('c', 6).unwrap()), Some(king));
+        assert_eq!(board.black_king, None);
+    }
+
+    #[test]
+    fn test_removing_rook_clears_castling_right() {
+        let mut board = Board::new();
+        board.remove_piece(Position::new('a', 1).unwrap());
+        assert!(!board.white_can_castle_queenside);
+        assert!(board.white_can_castle_kingside);
+        assert!(board.black_can_castle_queenside);
+    }
 
-        let moves = board.moves_in_directions(from, directions, Color::White);
+    #[test]
+    fn test_replacing_rook_clears_castling_right() {
+        let mut board = Board::new();
+        board.set_piece(Position::new('h', 8).unwrap(), Piece { color: Color::Black, kind: PieceType::Queen });
+        assert!(!board.black_can_castle_kingside);
+        assert!(board.black_can_castle_queenside);
+    }
+}
+#[cfg(test)]
+mod from_moves_tests {
+    use super::*;
 
-        assert!(moves.is_empty(), "Should not generate any moves from h8 in these directions");
+    fn mv(from: (char, u8), to: (char, u8)) -> Move {
+        Move::new(Position::new(from.0, from.1).unwrap(), Position::new(to.0, to.1).unwrap(), None)
     }
 
     #[test]
-    fn test_moves_in_multiple_directions() {
-        let board = Board::new();
-        let from = Position::new('d', 4).unwrap();
-        let directions = &[(1, 0), (0, 1)]; // East and North
+    fn test_from_moves_fools_mate() {
+        let moves = [
+            mv(('f', 2), ('f', 3)),
+            mv(('e', 7), ('e', 5)),
+            mv(('g', 2), ('g', 4)),
+            mv(('d', 8), ('h', 4)),
+        ];
+        let board = Board::from_moves(&moves).unwrap();
+        assert_eq!(board.game_state, GameState::Checkmate(Color::White));
+    }
 
-        let moves = board.moves_in_directions(from, directions, Color::White);
+    #[test]
+    fn test_apply_moves_rolls_back_on_error() {
+        let mut board = Board::new();
+        board.try_move(Position::new('d', 2).unwrap(), Position::new('d', 4).unwrap(), None).unwrap();
+        let before = board.clone();
+
+        let moves = [mv(('d', 7), ('d', 5)), mv(('e', 2), ('e', 4)), mv(('d', 5), ('d', 4))];
+        let (index, _) = board.apply_moves(&moves).unwrap_err();
+        assert_eq!(index, 2);
+        assert_eq!(board.squares, before.squares);
+        assert_eq!(board.turn, before.turn);
+        assert_eq!(board.en_passant_target, before.en_passant_target);
+        assert_eq!(board.halfmove_clock, before.halfmove_clock);
+        assert_eq!(board.fullmove_number, before.fullmove_number);
+        assert_eq!(board.position_history, before.position_history);
+        assert_eq!(board.to_string(), before.to_string());
+
+        board.apply_moves(&moves[..2]).unwrap();
+        assert_eq!(board.fullmove_number, 2);
+        assert_eq!(board.turn, Color::Black);
+    }
 
-        // d4 east: e4, f4, g4, h4
-        // d4 north: d5, d6, d7, d8
-        let expected_positions = [
-            ('e', 4), ('f', 4), ('g', 4), ('h', 4),
-            ('d', 5), ('d', 6), ('d', 7),
+    #[test]
+    fn test_from_moves_reports_illegal_ply() {
+        let moves = [
+            mv(('e', 2), ('e', 4)),
+            mv(('e', 7), ('e', 5)),
+            mv(('e', 4), ('e', 5)),
         ];
+        let err = Board::from_moves(&moves).unwrap_err();
+        assert!(err.contains("ply 2"), "Unexpected error: {err}");
+    }
 
-        assert_eq!(moves.len(), expected_positions.len());
-        for expected in expected_positions.iter() {
-            assert!(moves.contains(&Position::new(expected.0, expected.1).unwrap()));
-        }
+    #[test]
+    fn test_from_moves_empty_is_start_position() {
+        let board = Board::from_moves(&[]).unwrap();
+        assert_eq!(board.squares, Board::new().squares);
+        assert_eq!(board.turn, Color::White);
     }
 }
+
 #[cfg(test)]
-mod check_tests {
+mod uci_tests {
     use super::*;
 
     #[test]
-    fn test_not_in_check_starting_position() {
-        let board = Board::new();
-        assert!(!board.is_in_check(Color::White), "White should not be in check at start.");
-        assert!(!board.is_in_check(Color::Black), "Black should not be in check at start.");
+    fn test_uci_pawn_push() {
+        let mut board = Board::new();
+        board.make_uci_move("e2e4").unwrap();
+        assert_eq!(board.squares.get(&Position::new('e', 4).unwrap()).unwrap().kind, PieceType::Pawn);
+        assert_eq!(board.turn, Color::Black);
     }
 
     #[test]
-    fn test_in_check_simple_rook_attack() {
+    fn test_uci_promotion() {
         let pieces = vec![
+            ('a', 7, Color::White, PieceType::Pawn),
             ('e', 1, Color::White, PieceType::King),
-            ('e', 8, Color::Black, PieceType::Rook),
+            ('h', 5, Color::Black, PieceType::King),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-
-        assert!(board.is_in_check(Color::White), "White king should be in check from black rook.");
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.make_uci_move("a7a8q").unwrap();
+        assert_eq!(board.squares.get(&Position::new('a', 8).unwrap()).unwrap().kind, PieceType::Queen);
     }
 
     #[test]
-    fn test_not_in_check_rook_blocked() {
+    fn test_uci_castling() {
         let pieces = vec![
             ('e', 1, Color::White, PieceType::King),
-            ('e', 4, Color::White, PieceType::Pawn), // Blocking pawn
-            ('e', 8, Color::Black, PieceType::Rook),
+            ('h', 1, Color::White, PieceType::Rook),
+            ('e', 8, Color::Black, PieceType::King),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(!board.is_in_check(Color::White), "White king should not be in check (rook is blocked).");
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.set_castling_rights(true, true, true, true);
+        board.make_uci_move("e1g1").unwrap();
+        assert_eq!(board.squares.get(&Position::new('g', 1).unwrap()).unwrap().kind, PieceType::King);
+        assert_eq!(board.squares.get(&Position::new('f', 1).unwrap()).unwrap().kind, PieceType::Rook);
     }
 
     #[test]
-    fn test_in_check_diagonal_bishop_attack() {
-        let pieces = vec![
-            ('c', 1, Color::White, PieceType::King),
-            ('f', 4, Color::Black, PieceType::Bishop),
-        ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
+    fn test_uci_malformed_rejected() {
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(board.is_in_check(Color::White), "White king should be in check from bishop on diagonal.");
+        assert!(board.make_uci_move("e2e9").is_err());
+        assert!(board.make_uci_move("e2").is_err());
+        assert!(board.make_uci_move("a2a3x").is_err());
+        assert_eq!(board.turn, Color::White, "Rejected moves must not change the turn.");
     }
+}
 
-    #[test]
-    fn test_in_check_knight_attack() {
-        let pieces = vec![
-            ('e', 1, Color::White, PieceType::King),
-            ('d', 3, Color::Black, PieceType::Knight),
-        ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
-        let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(board.is_in_check(Color::White), "White king should be in check from knight.");
-    }
+#[cfg(test)]
+mod attack_tests {
+    use super::*;
 
     #[test]
-    fn test_in_check_queen_attack() {
+    fn test_attackers_knight_and_bishop() {
         let pieces = vec![
-            ('e', 1, Color::White, PieceType::King),
-            ('e', 5, Color::Black, PieceType::Queen),
+            ('f', 3, Color::White, PieceType::Knight),
+            ('b', 2, Color::White, PieceType::Bishop),
+            ('e', 5, Color::Black, PieceType::Pawn),
+            ('a', 8, Color::Black, PieceType::King),
+            ('h', 1, Color::White, PieceType::King),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(board.is_in_check(Color::White), "White king should be in check from queen along file.");
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        let attackers = board.attackers_of(sq('e', 5), Color::White);
+        assert_eq!(attackers.len(), 2);
+        assert!(attackers.contains(&sq('f', 3)));
+        assert!(attackers.contains(&sq('b', 2)));
     }
 
     #[test]
-    fn test_in_check_multiple_threats() {
+    fn test_attackers_excludes_blocked_slider() {
         let pieces = vec![
-            ('e', 1, Color::White, PieceType::King),
-            ('e', 8, Color::Black, PieceType::Rook),
-            ('a', 1, Color::Black, PieceType::Rook),
+            ('e', 1, Color::White, PieceType::Rook),
+            ('e', 3, Color::Black, PieceType::Pawn),
+            ('a', 1, Color::White, PieceType::Queen),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(board.is_in_check(Color::White), "White king should be in check from two directions.");
-    }
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
 
-    #[test]
-    fn test_not_in_check_empty_board() {
-        let board = Board::new();
-        assert!(!board.is_in_check(Color::White), "Empty board should not cause check (no kings).");
-        assert!(!board.is_in_check(Color::Black), "Empty board should not cause check (no kings).");
+        assert!(board.attackers_of(sq('e', 6), Color::White).is_empty());
+        assert_eq!(board.attackers_of(sq('e', 3), Color::White), vec![sq('e', 1)]);
+        assert_eq!(board.attackers_of(sq('d', 4), Color::White), vec![sq('a', 1)]);
     }
 
     #[test]
-    fn test_in_check_pawn_attack() {
+    fn test_attackers_pawns_and_kings() {
         let pieces = vec![
-            ('e', 4, Color::White, PieceType::King),
-            ('d', 5, Color::Black, PieceType::Pawn), // Pawn threatens e4
+            ('d', 4, Color::White, PieceType::Pawn),
+            ('f', 6, Color::Black, PieceType::Pawn),
+            ('e', 6, Color::Black, PieceType::King),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(board.is_in_check(Color::White), "White king should be in check from black pawn.");
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        assert_eq!(board.attackers_of(sq('e', 5), Color::White), vec![sq('d', 4)]);
+        assert!(board.attackers_of(sq('d', 5), Color::White).is_empty(), "Pawns do not attack straight ahead.");
+        let black = board.attackers_of(sq('e', 5), Color::Black);
+        assert!(black.contains(&sq('f', 6)));
+        assert!(black.contains(&sq('e', 6)));
+        assert_eq!(black.len(), 2);
     }
 
     #[test]
-    fn test_not_in_check_wrong_color_pawn() {
-        let pieces = vec![
-            ('e', 4, Color::Black, PieceType::King),
-            ('d', 5, Color::White, PieceType::Pawn), // White pawn can't threaten Black king backwards
+    fn test_threatened_pieces_reports_only_undefended() {
+        let mut pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('d', 4, Color::White, PieceType::Knight),
+            ('d', 8, Color::Black, PieceType::Rook),
+            ('h', 8, Color::Black, PieceType::King),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(!board.is_in_check(Color::Black), "White pawn should not check black king from above.");
-    }
+        board.initialize_custom(pieces.clone(), Color::White, GameState::Ongoing);
+        assert_eq!(board.threatened_pieces(Color::White), vec![sq('d', 4)]);
+        assert!(board.threatened_pieces(Color::Black).is_empty());
 
-    #[test]
-    fn test_not_checkmated_at_start() {
-        let board = Board::new();
-        assert!(!board.is_checkmate(Color::White), "White should not be checkmated at starting position.");
-        assert!(!board.is_checkmate(Color::Black), "Black should not be checkmated at starting position.");
+        pieces.push(('c', 3, Color::White, PieceType::Pawn));
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        assert!(board.threatened_pieces(Color::White).is_empty(), "The knight is defended by the pawn.");
     }
 }
 
 #[cfg(test)]
-mod checkmake_tests {
+mod chess960_tests {
     use super::*;
 
+    fn back_rank(board: &Board, rank: u8) -> Vec<(char, Piece)> {
+        ('a'..='h')
+            .map(|file| (file, *board.squares.get(&Position::new(file, rank).unwrap()).unwrap()))
+            .collect()
+    }
+
     #[test]
-    fn test_simple_check_but_not_checkmate() {
-        let pieces = vec![
-            ('e', 1, Color::White, PieceType::King),
-            ('e', 8, Color::Black, PieceType::Rook), // Black rook attacking, but king can move
-        ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
-        let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(board.is_in_check(Color::White), "White king should be in check.");
-        assert!(!board.is_checkmate(Color::White), "White king should not be checkmated (can escape).");
+    fn test_chess960_constraints_hold_over_many_seeds() {
+        for seed in 0..200 {
+            let board = Board::new_chess960(seed);
+            let white = back_rank(&board, 1);
+            let black = back_rank(&board, 8);
+
+            let bishops: Vec<char> = white.iter().filter(|(_, p)| p.kind == PieceType::Bishop).map(|(f, _)| *f).collect();
+            assert_eq!(bishops.len(), 2);
+            let parity = |file: char| (file as u8 - b'a') % 2;
+            assert_ne!(parity(bishops[0]), parity(bishops[1]), "seed {seed}: bishops share a color");
+
+            let rooks: Vec<char> = white.iter().filter(|(_, p)| p.kind == PieceType::Rook).map(|(f, _)| *f).collect();
+            let king = white.iter().find(|(_, p)| p.kind == PieceType::King).unwrap().0;
+            assert_eq!(rooks.len(), 2);
+            assert!(rooks[0] < king && king < rooks[1], "seed {seed}: king not between rooks");
+            assert_eq!(board.queenside_rook_file, rooks[0]);
+            assert_eq!(board.kingside_rook_file, rooks[1]);
+            assert_eq!(board.king_start_file, king);
+
+            assert_eq!(white.iter().filter(|(_, p)| p.kind == PieceType::Knight).count(), 2);
+            assert_eq!(white.iter().filter(|(_, p)| p.kind == PieceType::Queen).count(), 1);
+            for ((_, w), (_, b)) in white.iter().zip(&black) {
+                assert_eq!(w.kind, b.kind, "seed {seed}: black does not mirror white");
+                assert_eq!((w.color, b.color), (Color::White, Color::Black));
+            }
+        }
     }
 
     #[test]
-    fn test_simple_checkmate_by_rook_and_king_blocked() {
-        let pieces = vec![
-            ('a', 1, Color::White, PieceType::King),
-            ('a', 2, Color::Black, PieceType::Rook),
-            ('b', 2, Color::Black, PieceType::Rook),
-        ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
-        let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(board.is_in_check(Color::White), "White king should be in check.");
-        assert!(board.is_checkmate(Color::White), "White king should be checkmated (blocked on all sides).");
+    fn test_chess960_is_deterministic() {
+        assert_eq!(Board::new_chess960(7).squares, Board::new_chess960(7).squares);
     }
 
     #[test]
-    fn test_simple_check_by_rook_and_king_can_take_to_escape() {
+    fn test_chess960_castling_with_nonstandard_rook_files() {
         let pieces = vec![
-            ('a', 1, Color::White, PieceType::King),
-            ('a', 2, Color::Black, PieceType::Rook),
-            ('b', 3, Color::Black, PieceType::Rook),
+            ('b', 1, Color::White, PieceType::King),
+            ('a', 1, Color::White, PieceType::Rook),
+            ('f', 1, Color::White, PieceType::Rook),
+            ('e', 8, Color::Black, PieceType::King),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(board.is_in_check(Color::White), "White king should be in check.");
-        assert!(!board.is_checkmate(Color::White), "White king should be checkmated (blocked on all sides).");
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.set_castling_rights(true, true, true, true);
+        board.king_start_file = 'b';
+        board.kingside_rook_file = 'f';
+        board.queenside_rook_file = 'a';
+
+        let mut kingside = board.clone();
+        kingside.try_move(Position::new('b', 1).unwrap(), Position::new('g', 1).unwrap(), None).unwrap();
+        assert_eq!(kingside.squares.get(&Position::new('g', 1).unwrap()).unwrap().kind, PieceType::King);
+        assert_eq!(kingside.squares.get(&Position::new('f', 1).unwrap()).unwrap().kind, PieceType::Rook);
+        assert!(!kingside.squares.contains_key(&Position::new('b', 1).unwrap()));
+
+        // The king only travels one file queenside, so castling is written king-onto-rook
+        assert!(board.get_legal_moves(Position::new('b', 1).unwrap()).contains(&Position::new('a', 1).unwrap()));
+        board.try_move(Position::new('b', 1).unwrap(), Position::new('a', 1).unwrap(), None).unwrap();
+        assert_eq!(board.squares.get(&Position::new('c', 1).unwrap()).unwrap().kind, PieceType::King);
+        assert_eq!(board.squares.get(&Position::new('d', 1).unwrap()).unwrap().kind, PieceType::Rook);
+        assert!(!board.squares.contains_key(&Position::new('a', 1).unwrap()));
+        assert!(!board.white_can_castle_kingside && !board.white_can_castle_queenside);
     }
 
     #[test]
-    fn test_checkmate_by_queen() {
+    fn test_chess960_castling_blocked_by_piece_on_destination() {
         let pieces = vec![
-            ('a', 1, Color::White, PieceType::King),
-            ('h', 1, Color::Black, PieceType::Queen),
-            ('b', 3, Color::Black, PieceType::King),
+            ('b', 1, Color::White, PieceType::King),
+            ('f', 1, Color::White, PieceType::Rook),
+            ('h', 1, Color::White, PieceType::Knight),
+            ('g', 1, Color::White, PieceType::Bishop),
+            ('e', 8, Color::Black, PieceType::King),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(board.is_in_check(Color::White), "White king should be in check from queen.");
-        assert!(board.is_checkmate(Color::White), "White king should be checkmated (no escape).");
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.king_start_file = 'b';
+        board.kingside_rook_file = 'f';
+
+        assert!(!board.get_legal_moves(Position::new('b', 1).unwrap()).contains(&Position::new('g', 1).unwrap()));
+        assert!(board.try_move(Position::new('b', 1).unwrap(), Position::new('f', 1).unwrap(), None).is_err());
     }
+}
+#[cfg(test)]
+mod perft_tests {
+    use super::*;
 
     #[test]
-    fn test_not_checkmate_king_can_escape() {
-        let pieces = vec![
-            ('a', 1, Color::White, PieceType::King),
-            ('a', 3, Color::Black, PieceType::Queen),
-        ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
-        let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(board.is_in_check(Color::White), "White king should be in check.");
-        assert!(!board.is_checkmate(Color::White), "White king should be able to escape (move).");
+    fn test_perft_start_position() {
+        let board = Board::new();
+        assert_eq!(board.perft(1), 20);
+        assert_eq!(board.perft(2), 400);
+        assert_eq!(board.perft(3), 8902);
     }
 
     #[test]
-    fn test_checkmate_fools_mate() {
+    fn test_perft_divide_sums_to_perft() {
+        let board = Board::new();
+        for depth in 1..=3 {
+            let divide = board.perft_divide(depth);
+            assert_eq!(divide.len(), 20);
+            assert_eq!(divide.iter().map(|(_, nodes)| nodes).sum::<u64>(), board.perft(depth));
+        }
+        let e2e4 = Move::from_uci("e2e4").unwrap();
+        assert_eq!(board.perft_divide(3).iter().find(|(mv, _)| *mv == e2e4).map(|(_, n)| *n), Some(600));
+        assert!(board.perft_divide(0).is_empty());
+    }
+
+    #[test]
+    fn test_promotion_generates_four_moves() {
+        let pieces = vec![
+            ('b', 7, Color::White, PieceType::Pawn),
+            ('h', 1, Color::White, PieceType::King),
+            ('h', 8, Color::Black, PieceType::King),
+        ];
         let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
 
-        // Simulate Fool's Mate
-        board.try_move(Position::new('f', 2).unwrap(), Position::new('f', 3).unwrap(), None).unwrap();
-        board.try_move(Position::new('e', 7).unwrap(), Position::new('e', 5).unwrap(), None).unwrap();
-        board.try_move(Position::new('g', 2).unwrap(), Position::new('g', 4).unwrap(), None).unwrap();
-        board.try_move(Position::new('d', 8).unwrap(), Position::new('h', 4).unwrap(), None).unwrap();
+        let pawn_moves: Vec<Move> = board
+            .get_all_legal_moves()
+            .into_iter()
+            .filter(|mv| mv.from == Position::new('b', 7).unwrap())
+            .collect();
+        assert_eq!(pawn_moves.len(), 4);
+        for kind in PieceType::promotion_choices() {
+            assert!(pawn_moves.contains(&Move::new(Position::new('b', 7).unwrap(), Position::new('b', 8).unwrap(), Some(kind))));
+        }
 
-        assert!(board.is_in_check(Color::White), "White should be in check.");
-        assert!(board.is_checkmate(Color::White), "White should be checkmated in Fool's Mate.");
+        // Four promotions plus three king moves
+        assert_eq!(board.perft(1), 7);
     }
 
-    #[test]
-    fn test_not_checkmate_not_in_check() {
+    /// White to move can take the last black pawn with the knight, drawing by insufficient material.
+    fn knight_takes_last_pawn() -> Board {
         let pieces = vec![
             ('e', 1, Color::White, PieceType::King),
-            ('a', 8, Color::Black, PieceType::Rook),
+            ('b', 1, Color::White, PieceType::Knight),
+            ('h', 8, Color::Black, PieceType::King),
+            ('c', 3, Color::Black, PieceType::Pawn),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        assert!(!board.is_in_check(Color::White), "White king should not be in check.");
-        assert!(!board.is_checkmate(Color::White), "White king should not be checkmated if not in check.");
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board
     }
 
     #[test]
-    fn test_checkmate_edge_case_empty_board() {
-        let board = Board::new();
+    fn test_perft_counts_moves_below_adjudicated_draw() {
+        let mut board = knight_takes_last_pawn();
+        board.try_move(Position::new('b', 1).unwrap(), Position::new('c', 3).unwrap(), None).unwrap();
+        assert_eq!(board.game_state, GameState::Draw(DrawReason::InsufficientMaterial));
+        assert_eq!(board.perft(1), 3);
+        // Each black king move is answered by five king moves and eight knight moves
+        assert_eq!(board.perft(2), 39);
+    }
 
-        // No kings on the board technically — behavior undefined, but should NOT panic
-        assert!(!board.is_checkmate(Color::White), "Empty board should not panic or cause checkmate.");
-        assert!(!board.is_checkmate(Color::Black), "Empty board should not panic or cause checkmate.");
+    #[test]
+    fn test_perft_divide_counts_moves_below_adjudicated_draw() {
+        let board = knight_takes_last_pawn();
+        let b1c3 = Move::from_uci("b1c3").unwrap();
+        for depth in 1..=3 {
+            let divide = board.perft_divide(depth);
+            assert_eq!(divide.iter().map(|(_, nodes)| nodes).sum::<u64>(), board.perft(depth));
+        }
+        assert_eq!(board.perft_divide(3).iter().find(|(mv, _)| *mv == b1c3).map(|(_, n)| *n), Some(39));
+    }
+}
+#[cfg(test)]
+mod is_legal_move_tests {
+    use super::*;
+
+    #[test]
+    fn test_opening_pawn_push_is_legal() {
+        let board = Board::new();
+        assert!(board.is_legal_move(Position::new('e', 2).unwrap(), Position::new('e', 4).unwrap(), None));
     }
 
     #[test]
-    fn test_checkmate_smothered_mate() {
+    fn test_move_leaving_king_in_check_is_illegal() {
         let pieces = vec![
-            ('h', 8, Color::Black, PieceType::King),
-            ('h', 7, Color::Black, PieceType::Pawn),
-            ('g', 7, Color::Black, PieceType::King),
-            ('g', 8, Color::Black, PieceType::Rook),
-            ('f', 7, Color::White, PieceType::Knight),
+            ('e', 1, Color::White, PieceType::King),
+            ('e', 2, Color::White, PieceType::Rook),
+            ('e', 8, Color::Black, PieceType::Rook),
+            ('a', 8, Color::Black, PieceType::King),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        // No kings on the board technically — behavior undefined, but should NOT panic
-        assert!(!board.is_checkmate(Color::White), "Empty board should not panic or cause checkmate.");
-        assert!(!board.is_checkmate(Color::Black), "Empty board should not panic or cause checkmate.");
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        let before = board.squares.clone();
+
+        assert!(!board.is_legal_move(Position::new('e', 2).unwrap(), Position::new('d', 2).unwrap(), None));
+        assert!(board.is_legal_move(Position::new('e', 2).unwrap(), Position::new('e', 5).unwrap(), None));
+        assert_eq!(board.squares, before);
+    }
+
+    #[test]
+    fn test_wrong_turn_is_illegal() {
+        let board = Board::new();
+        assert!(!board.is_legal_move(Position::new('e', 7).unwrap(), Position::new('e', 5).unwrap(), None));
     }
 }
 #[cfg(test)]
-mod try_move_tests {
+mod mate_search_tests {
     use super::*;
 
-    #[test]
-    fn test_try_move_successful_normal_move() {
+    fn back_rank(h_pawn_rank: u8) -> Board {
+        let pieces = vec![
+            ('g', 1, Color::White, PieceType::King),
+            ('a', 1, Color::White, PieceType::Rook),
+            ('g', 8, Color::Black, PieceType::King),
+            ('f', 7, Color::Black, PieceType::Pawn),
+            ('g', 7, Color::Black, PieceType::Pawn),
+            ('h', h_pawn_rank, Color::Black, PieceType::Pawn),
+        ];
         let mut board = Board::new();
-        let result = board.try_move(Position::new('e', 2).unwrap(), Position::new('e', 4).unwrap(), None);
-        assert!(result.is_ok(), "Expected pawn move from e2 to e4 to succeed.");
-        assert_eq!(board.turn, Color::Black, "Turn should switch to Black after move.");
-        assert_eq!(board.game_state, GameState::Ongoing, "Game should continue after normal move.");
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board
     }
 
     #[test]
-    fn test_try_move_no_piece_at_start() {
-        let mut board = Board::new();
-        let result = board.try_move(Position::new('e', 3).unwrap(), Position::new('e', 4).unwrap(), None);
-        assert!(result.is_err(), "Expected error when no piece at starting position.");
+    fn test_finds_back_rank_mate_in_one() {
+        let board = back_rank(7);
+        let line = board.has_mate_in(Color::White, 1).unwrap();
+        assert_eq!(line, vec![Move::from_uci("a1a8").unwrap()]);
     }
 
     #[test]
-    fn test_try_move_illegal_move_attempt() {
-        let mut board = Board::new();
-        let result = board.try_move(Position::new('e', 2).unwrap(), Position::new('e', 5).unwrap(), None); // Illegal: pawn can't jump to e5 directly
-        assert!(result.is_err(), "Expected illegal move error for pawn jumping 3 spaces.");
+    fn test_no_mate_when_king_has_luft() {
+        let board = back_rank(6);
+        assert!(board.has_mate_in(Color::White, 1).is_none());
     }
 
     #[test]
-    fn test_try_move_not_players_turn() {
-        let mut board = Board::new();
-        board.turn = Color::Black;
-        let result = board.try_move(Position::new('e', 2).unwrap(), Position::new('e', 4).unwrap(), None);
-        assert!(result.is_err(), "Expected error when moving out of turn.");
+    fn test_no_mate_for_side_not_to_move() {
+        let board = back_rank(7);
+        assert!(board.has_mate_in(Color::Black, 1).is_none());
+    }
+}
+#[cfg(test)]
+mod san_tests {
+    use super::*;
+
+    #[test]
+    fn test_san_round_trip_from_start() {
+        let board = Board::new();
+        for mv in board.get_all_legal_moves() {
+            let san = board.move_to_san(&mv).unwrap();
+            assert_eq!(board.parse_san(&san).unwrap(), mv, "{san}");
+        }
     }
 
     #[test]
-    fn test_try_move_into_check_disallowed() {
+    fn test_san_disambiguates_by_file() {
         let pieces = vec![
-            ('a', 1, Color::White, PieceType::King),
-            ('b', 2, Color::Black, PieceType::Rook),
+            ('a', 1, Color::White, PieceType::Rook),
+            ('h', 1, Color::White, PieceType::Rook),
+            ('e', 2, Color::White, PieceType::King),
+            ('e', 8, Color::Black, PieceType::King),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        let result = board.try_move(Position::new('a', 1).unwrap(), Position::new('b', 1).unwrap(), None);
-        assert!(result.is_err(), "Expected error when moving into check.");
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        assert_eq!(board.move_to_san(&Move::new(sq('a', 1), sq('d', 1), None)).unwrap(), "Rad1");
+        assert_eq!(board.parse_san("Rhd1").unwrap(), Move::new(sq('h', 1), sq('d', 1), None));
+        assert!(board.parse_san("Rd1").is_err());
     }
 
     #[test]
-    fn test_try_move_checkmate_after_move() {
+    fn test_san_promotion_with_mate() {
         let pieces = vec![
-            ('a', 1, Color::White, PieceType::King),
-            ('d', 8, Color::Black, PieceType::Queen),
-            ('b', 3, Color::Black, PieceType::King),
+            ('a', 7, Color::White, PieceType::Pawn),
+            ('b', 6, Color::White, PieceType::King),
+            ('h', 8, Color::Black, PieceType::King),
+            ('g', 7, Color::Black, PieceType::Pawn),
+            ('h', 7, Color::Black, PieceType::Pawn),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        board.turn = Color::Black;
-        let result = board.try_move(Position::new('d', 8).unwrap(), Position::new('d', 1).unwrap(), None);
-        assert!(result.is_ok(), "Expected queen move to e5 to succeed.");
-        
-        match board.game_state {
-            GameState::Checkmate(Color::White) => {},
-            other => panic!("Expected White to be checkmated after move, found {:?}", other),
-        }
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+
+        let mv = Move::new(sq('a', 7), sq('a', 8), Some(PieceType::Queen));
+        assert_eq!(board.move_to_san(&mv).unwrap(), "a8=Q#");
+        assert_eq!(board.parse_san("a8Q").unwrap(), mv);
     }
 
     #[test]
-    fn test_try_move_no_checkmate_if_king_can_escape() {
+    fn test_san_en_passant_names_destination() {
         let pieces = vec![
+            ('e', 5, Color::White, PieceType::Pawn),
+            ('c', 5, Color::White, PieceType::Pawn),
+            ('d', 7, Color::Black, PieceType::Pawn),
             ('e', 1, Color::White, PieceType::King),
-            ('f', 3, Color::Black, PieceType::Queen),
+            ('e', 8, Color::Black, PieceType::King),
         ];
-        let turn = Color::Black;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        let result = board.try_move(Position::new('f', 3).unwrap(), Position::new('f', 2).unwrap(), None);
-        assert!(result.is_ok(), "Expected move to succeed.");
-        assert_eq!(board.game_state, GameState::Ongoing, "Game should remain ongoing (king can escape).");
-    }
-
-    #[test]
-    fn test_try_move_stalemate_placeholder() {
-        let board = Board::new();
+        board.initialize_custom(pieces, Color::Black, GameState::Ongoing);
+        board.make_uci_move("d7d5").unwrap();
 
-        assert_eq!(board.game_state, GameState::Ongoing, "Empty board should be ongoing (no stalemate yet).");
+        // Both pawns can take en passant, so the file tells them apart
+        let mv = Move::new(sq('e', 5), sq('d', 6), None);
+        assert_eq!(board.move_to_san(&mv).unwrap(), "exd6");
+        assert_eq!(board.move_to_san(&Move::new(sq('c', 5), sq('d', 6), None)).unwrap(), "cxd6");
+        assert_eq!(board.parse_san("exd6").unwrap(), mv);
     }
 
     #[test]
-    fn test_try_move_fools_mate_checkmate() {
+    fn test_san_castling_with_check() {
+        let pieces = vec![
+            ('e', 1, Color::White, PieceType::King),
+            ('a', 1, Color::White, PieceType::Rook),
+            ('h', 1, Color::White, PieceType::Rook),
+            ('d', 8, Color::Black, PieceType::King),
+        ];
         let mut board = Board::new();
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.set_castling_rights(true, true, false, false);
 
-        // Fool's Mate (quickest checkmate in chess)
-        board.try_move(Position::new('f', 2).unwrap(), Position::new('f', 3).unwrap(), None).unwrap();
-        board.try_move(Position::new('e', 7).unwrap(), Position::new('e', 5).unwrap(), None).unwrap();
-        board.try_move(Position::new('g', 2).unwrap(), Position::new('g', 4).unwrap(), None).unwrap();
-        board.try_move(Position::new('d', 8).unwrap(), Position::new('h', 4).unwrap(), None).unwrap();
-
-        match board.game_state {
-            GameState::Checkmate(Color::White) => {},
-            other => panic!("Expected White to be checkmated in Fool's Mate, found {:?}", other),
-        }
+        let queenside = Move::new(sq('e', 1), sq('c', 1), None);
+        assert_eq!(board.move_to_san(&queenside).unwrap(), "O-O-O+");
+        assert_eq!(board.move_to_san(&Move::new(sq('e', 1), sq('g', 1), None)).unwrap(), "O-O");
+        assert_eq!(board.parse_san("O-O-O+").unwrap(), queenside);
     }
 }
 #[cfg(test)]
-mod promotion_tests {
+mod occupancy_tests {
     use super::*;
-    #[test]
-    fn test_pawn_promotion_to_queen() {
-        let mut board = Board::new();
-        board.squares.clear();
 
-        // White pawn at 7th rank
-        board.squares.insert(Position::new('a', 7).unwrap(), Piece { color: Color::White, kind: PieceType::Pawn });
-        board.turn = Color::White;
+    /// The original `HashMap` ray scan that the occupancy bitboards replaced.
+    fn reference_slides(board: &Board, from: Position, directions: &[(i8, i8)], color: Color) -> Vec<Position> {
+        let mut moves = Vec::new();
+        for (df, dr) in directions {
+            let mut distance = 1;
+            while let Some(pos) = (from.file as u8)
+                .checked_add_signed(df * distance)
+                .zip(from.rank.checked_add_signed(dr * distance))
+                .and_then(|(file, rank)| Position::new(file as char, rank))
+            {
+                match board.squares.get(&pos) {
+                    Some(other) => {
+                        if other.color != color {
+                            moves.push(pos);
+                        }
+                        break;
+                    }
+                    None => moves.push(pos),
+                }
+                distance += 1;
+            }
+        }
+        moves
+    }
 
-        // Move to 8th rank with promotion
-        let from = Position::new('a', 7).unwrap();
-        let to = Position::new('a', 8).unwrap();
-        board.try_move(from, to, Some(PieceType::Queen)).unwrap();
+    fn assert_matches_reference(board: &Board) {
+        let all = [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (-1, 1), (1, -1), (-1, -1)];
+        for (pos, piece) in &board.squares {
+            assert_eq!(
+                board.moves_in_directions(*pos, &all, piece.color),
+                reference_slides(board, *pos, &all, piece.color)
+            );
+        }
+    }
 
-        let piece = board.squares.get(&to).unwrap();
-        assert_eq!(piece.kind, PieceType::Queen);
-        assert_eq!(piece.color, Color::White);
+    #[test]
+    fn test_occupancy_matches_squares_through_perft_tree() {
+        let board = Board::new();
+        assert_matches_reference(&board);
+        for mv in board.get_all_legal_moves() {
+            let mut child = board.clone();
+            child.try_move(mv.from, mv.to, mv.promotion).unwrap();
+            assert_matches_reference(&child);
+            for reply in child.get_all_legal_moves() {
+                let mut grandchild = child.clone();
+                grandchild.try_move(reply.from, reply.to, reply.promotion).unwrap();
+                assert_matches_reference(&grandchild);
+                let mut rebuilt = grandchild.clone();
+                rebuilt.sync_caches();
+                assert_eq!(grandchild.occupancy, rebuilt.occupancy);
+            }
+        }
+        assert_eq!(board.perft(3), 8902);
     }
 
     #[test]
-    fn test_pawn_promotion_checkmate() {
-        let pieces = vec![
-            ('h', 8, Color::Black, PieceType::Rook),
-            ('h', 7, Color::Black, PieceType::King),
-            ('g', 7, Color::Black, PieceType::Pawn),
-            ('g', 8, Color::Black, PieceType::Bishop),
-            ('g', 8, Color::Black, PieceType::Bishop),
-            ('h', 6, Color::Black, PieceType::Rook),
-            ('f', 7, Color::White, PieceType::Pawn),
-        ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
+    fn test_occupancy_follows_castling_and_en_passant() {
+        let board = Board::from_moves(&[
+            Move::from_uci("e2e4").unwrap(),
+            Move::from_uci("a7a6").unwrap(),
+            Move::from_uci("e4e5").unwrap(),
+            Move::from_uci("d7d5").unwrap(),
+            Move::from_uci("e5d6").unwrap(),
+            Move::from_uci("a6a5").unwrap(),
+            Move::from_uci("g1f3").unwrap(),
+            Move::from_uci("a5a4").unwrap(),
+            Move::from_uci("f1e2").unwrap(),
+            Move::from_uci("a4a3").unwrap(),
+            Move::from_uci("e1g1").unwrap(),
+        ])
+        .unwrap();
+        let mut rebuilt = board.clone();
+        rebuilt.sync_caches();
+        assert_eq!(board.occupancy, rebuilt.occupancy);
+        assert!(!board.squares.contains_key(&Position::new('d', 5).unwrap()));
+        assert_matches_reference(&board);
+    }
+
+    #[test]
+    fn test_removed_piece_opens_lines() {
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-        let from = {Position { file: ('f'), rank: (7) }};
-        let to = {Position { file: ('f'), rank: (8) }};
-        board.try_move(from, to, Some(PieceType::Knight)).unwrap();
+        board.remove_piece(Position::new('e', 2).unwrap());
+        let mut queen_moves = board.get_legal_moves(Position::new('d', 1).unwrap());
+        queen_moves.sort_by_key(|pos| (pos.file, pos.rank));
+        let expected: Vec<Position> =
+            [('e', 2), ('f', 3), ('g', 4), ('h', 5)].iter().map(|&(f, r)| Position::new(f, r).unwrap()).collect();
+        assert_eq!(queen_moves, expected);
+    }
 
-        assert_eq!(GameState::Checkmate(Color::Black), board.game_state);
+    #[test]
+    fn test_placed_piece_attacks_immediately() {
+        let mut board = Board::empty();
+        let e4 = Position::new('e', 4).unwrap();
+        board.set_piece(e4, Piece { color: Color::White, kind: PieceType::Queen });
+        assert_eq!(board.attackers_of(Position::new('e', 8).unwrap(), Color::White), vec![e4]);
     }
 }
 #[cfg(test)]
-mod castle_tests {
+mod king_cache_tests {
     use super::*;
 
     #[test]
-    fn test_white_kingside_castling() {
-        let pieces = vec![
-            ('e', 1, Color::White, PieceType::King),
-            ('h', 1, Color::White, PieceType::Rook),
-        ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
+    fn test_find_king() {
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-
-        assert!(board.try_move(Position::new('e', 1).unwrap(), Position::new('g', 1).unwrap(), None).is_ok());
+        assert_eq!(board.find_king(Color::White), Some(sq('e', 1)));
+        assert_eq!(board.find_king(Color::Black), Some(sq('e', 8)));
+        assert_eq!(Board::empty().find_king(Color::White), None);
 
-        assert_eq!(board.squares.get(&Position::new('g', 1).unwrap()).unwrap().kind, PieceType::King, "King not at g1");
-        assert_eq!(board.squares.get(&Position::new('f', 1).unwrap()).unwrap().kind, PieceType::Rook, "Rook not at f1");
+        board.remove_piece(sq('e', 8));
+        assert_eq!(board.find_king(Color::Black), None);
+        assert!(!board.is_in_check(Color::Black));
     }
+
     #[test]
-    fn test_white_kingside_castling_with_piece_blocking() {
-        let pieces = vec![
-            ('e', 1, Color::White, PieceType::King),
-            ('h', 1, Color::White, PieceType::Rook),
-            ('g', 1, Color::White, PieceType::Queen),
-        ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
+    fn test_king_cache_follows_king_move() {
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-
-        assert!(board.try_move(Position::new('e', 1).unwrap(), Position::new('g', 1).unwrap(), None).is_err());
+        assert_eq!((board.white_king, board.black_king), (Some(sq('e', 1)), Some(sq('e', 8))));
+        board.make_uci_move("e2e4").unwrap();
+        board.make_uci_move("e7e5").unwrap();
+        board.make_uci_move("e1e2").unwrap();
+        assert_eq!(board.white_king, Some(sq('e', 2)));
+        assert_eq!(board.black_king, Some(sq('e', 8)));
     }
 
     #[test]
-    fn test_white_kingside_castling_with_check_blocking() {
+    fn test_king_cache_follows_castling() {
         let pieces = vec![
             ('e', 1, Color::White, PieceType::King),
-            ('h', 1, Color::White, PieceType::Rook),
-            ('g', 5, Color::Black, PieceType::Queen),
+            ('a', 1, Color::White, PieceType::Rook),
+            ('e', 8, Color::Black, PieceType::King),
+            ('h', 8, Color::Black, PieceType::Rook),
         ];
-        let turn = Color::White;
-        let game_state = GameState::Ongoing;
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-
-        assert!(board.try_move(Position::new('e', 1).unwrap(), Position::new('g', 1).unwrap(), None).is_err());
+        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.set_castling_rights(true, true, true, true);
+        board.try_move(sq('e', 1), sq('c', 1), None).unwrap();
+        board.try_move(sq('e', 8), sq('g', 8), None).unwrap();
+        assert_eq!(board.white_king, Some(sq('c', 1)));
+        assert_eq!(board.black_king, Some(sq('g', 8)));
     }
 
     #[test]
-    fn test_black_queenside_castling() {
-        let pieces = vec![
-            ('e', 8, Color::Black, PieceType::King),
-            ('a', 8, Color::Black, PieceType::Rook),
-        ];
-        let turn = Color::Black;
-        let game_state = GameState::Ongoing;
+    fn test_king_cache_after_custom_initialization() {
         let mut board = Board::new();
-        board.initialize_custom(pieces, turn, game_state);
-
-        assert!(board.try_move(Position::new('e', 8).unwrap(), Position::new('c', 8).unwrap(), None).is_ok());
-
-        assert_eq!(board.squares.get(&Position::new('c', 8).unwrap()).unwrap().kind, PieceType::King);
-        assert_eq!(board.squares.get(&Position::new('d', 8).unwrap()).unwrap().kind, PieceType::Rook);
+        board.initialize_custom(
+            vec![('b', 3, Color::White, PieceType::King), ('f', 2, Color::Black, PieceType::Queen)],
+            Color::White,
+            GameState::Ongoing,
+        );
+        assert_eq!(board.white_king, Some(sq('b', 3)));
+        assert_eq!(board.black_king, None);
+        assert!(!board.is_in_check(Color::Black));
     }
 }
-
 #[cfg(test)]
-mod get_legal_moves_tests {
+mod make_unmake_tests {
     use super::*;
 
-    #[test]
-    fn test_pawn_initial_double_move() {
-        let pieces = vec![
-            ('e', 2, Color::White, PieceType::Pawn),
-        ];
-        let mut board = Board::new();
-        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+    fn snapshot(board: &Board) -> impl PartialEq + std::fmt::Debug {
+        (
+            board.squares.clone(),
+            board.turn,
+            board.en_passant_target,
+            [
+                board.white_can_castle_kingside,
+                board.white_can_castle_queenside,
+                board.black_can_castle_kingside,
+                board.black_can_castle_queenside,
+            ],
+            board.occupancy,
+            (board.white_king, board.black_king),
+        )
+    }
 
-        let moves = board.get_legal_moves(Position::new('e', 2).unwrap());
-        assert!(moves.contains(&Position::new('e', 3).unwrap()), "Pawn should move forward 1 square.");
-        assert!(moves.contains(&Position::new('e', 4).unwrap()), "Pawn should move forward 2 squares on first move.");
+    /// Legal moves computed the way they were before make/unmake: clone, force the move, test for check.
+    fn reference_legal_moves(board: &Board) -> Vec<(Position, Position)> {
+        let mut moves = Vec::new();
+        for (&from, piece) in &board.squares {
+            if piece.color != board.turn {
+                continue;
+            }
+            for to in board.get_legal_moves(from) {
+                let mut clone = board.clone();
+                let legal = match board.castling_side(piece.color, from, to) {
+                    Some(kingside) if piece.kind == PieceType::King => clone.try_castle(piece.color, kingside).is_ok(),
+                    _ => clone.force_move(from, to).is_ok() && !clone.is_in_check(piece.color),
+                };
+                if legal {
+                    moves.push((from, to));
+                }
+            }
+        }
+        moves.sort_by_key(|(from, to)| (from.file, from.rank, to.file, to.rank));
+        moves
+    }
+
+    fn generated_moves(board: &Board) -> Vec<(Position, Position)> {
+        let mut moves: Vec<_> = board.get_all_legal_moves().into_iter().map(|mv| (mv.from, mv.to)).collect();
+        moves.sort_by_key(|(from, to)| (from.file, from.rank, to.file, to.rank));
+        moves.dedup();
+        moves
+    }
+
+    #[test]
+    fn test_make_unmake_restores_every_position() {
+        let board = Board::new();
+        let mut scratch = board.clone();
+        for mv in board.get_all_legal_moves() {
+            let before = snapshot(&scratch);
+            let undo = scratch.make(mv).unwrap();
+            let mut child = scratch.clone();
+            for reply in child.clone().get_all_legal_moves() {
+                let inner = snapshot(&child);
+                let undo = child.make(reply).unwrap();
+                child.unmake(undo);
+                assert_eq!(snapshot(&child), inner);
+            }
+            scratch.unmake(undo);
+            assert_eq!(snapshot(&scratch), before);
+        }
     }
 
     #[test]
-    fn test_pawn_blocked_forward() {
+    fn test_make_unmake_special_moves() {
         let pieces = vec![
-            ('e', 2, Color::White, PieceType::Pawn),
-            ('e', 3, Color::Black, PieceType::Pawn),
+            ('e', 1, Color::White, PieceType::King),
+            ('h', 1, Color::White, PieceType::Rook),
+            ('b', 7, Color::White, PieceType::Pawn),
+            ('e', 5, Color::White, PieceType::Pawn),
+            ('d', 5, Color::Black, PieceType::Pawn),
+            ('a', 8, Color::Black, PieceType::Rook),
+            ('e', 8, Color::Black, PieceType::King),
         ];
         let mut board = Board::new();
         board.initialize_custom(pieces, Color::White, GameState::Ongoing);
-
-        let moves = board.get_legal_moves(Position::new('e', 2).unwrap());
-        assert!(moves.is_empty(), "Pawn should not move if blocked.");
+        board.en_passant_target = Some(Position::new('d', 6).unwrap());
+
+        for uci in ["e1g1", "e5d6", "b7a8n", "b7b8q"] {
+            let before = snapshot(&board);
+            let undo = board.make(Move::from_uci(uci).unwrap()).unwrap();
+            assert_ne!(snapshot(&board), before, "{uci}");
+            board.unmake(undo);
+            assert_eq!(snapshot(&board), before, "{uci}");
+        }
     }
 
     #[test]
-    fn test_pawn_capture_diagonal() {
-        let pieces = vec![
-            ('d', 4, Color::White, PieceType::Pawn),
-            ('c', 5, Color::Black, PieceType::Pawn),
-            ('e', 5, Color::Black, PieceType::Pawn),
-        ];
-        let mut board = Board::new();
-        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+    fn test_move_generation_matches_clone_based_reference() {
+        let board = Board::new();
+        assert_eq!(generated_moves(&board), reference_legal_moves(&board));
+        for mv in board.get_all_legal_moves() {
+            let mut child = board.clone();
+            child.try_move(mv.from, mv.to, mv.promotion).unwrap();
+            assert_eq!(generated_moves(&child), reference_legal_moves(&child));
+            for reply in child.get_all_legal_moves() {
+                let mut grandchild = child.clone();
+                grandchild.try_move(reply.from, reply.to, reply.promotion).unwrap();
+                assert_eq!(generated_moves(&grandchild), reference_legal_moves(&grandchild));
+            }
+        }
+    }
+}
+#[cfg(test)]
+mod move_kind_tests {
+    use super::*;
 
-        let moves = board.get_legal_moves(Position::new('d', 4).unwrap());
-        assert!(moves.contains(&Position::new('c', 5).unwrap()), "Pawn should capture diagonally left.");
-        assert!(moves.contains(&Position::new('e', 5).unwrap()), "Pawn should capture diagonally right.");
+    fn kind_of(board: &Board, from: Position, to: Position) -> MoveKind {
+        board
+            .get_all_legal_moves_classified()
+            .into_iter()
+            .find(|(mv, _)| mv.from == from && mv.to == to)
+            .map(|(_, kind)| kind)
+            .unwrap()
     }
 
     #[test]
-    fn test_knight_movement_open_board() {
-        let pieces = vec![
-            ('d', 4, Color::White, PieceType::Knight),
-        ];
+    fn test_queen_captures_pawn() {
         let mut board = Board::new();
-        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
-
-        let moves = board.get_legal_moves(Position::new('d', 4).unwrap());
-        let expected_positions = [
-            ('c', 6), ('e', 6),
-            ('b', 5), ('f', 5),
-            ('b', 3), ('f', 3),
-            ('c', 2), ('e', 2),
-        ];
-        for (file, rank) in expected_positions.iter() {
-            assert!(moves.contains(&Position::new(*file, *rank).unwrap()), "Knight move to {}{} missing.", file, rank);
+        for mv in ["e2e4", "d7d5", "d1g4", "c8d7"] {
+            board.make_uci_move(mv).unwrap();
         }
+        assert_eq!(kind_of(&board, sq('g', 4), sq('g', 7)), MoveKind::Capture(PieceType::Pawn));
+        assert_eq!(kind_of(&board, sq('e', 4), sq('d', 5)), MoveKind::Capture(PieceType::Pawn));
+        assert_eq!(kind_of(&board, sq('a', 2), sq('a', 4)), MoveKind::Quiet);
     }
 
     #[test]
-    fn test_rook_movement_clear() {
-        let pieces = vec![
-            ('d', 4, Color::White, PieceType::Rook),
-        ];
+    fn test_en_passant_castle_and_promotion() {
         let mut board = Board::new();
-        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        for mv in ["e2e4", "a7a6", "e4e5", "d7d5", "g1f3", "a6a5", "f1e2", "a5a4"] {
+            board.make_uci_move(mv).unwrap();
+        }
+        assert_eq!(kind_of(&board, sq('e', 1), sq('g', 1)), MoveKind::Castle);
 
-        let moves = board.get_legal_moves(Position::new('d', 4).unwrap());
-        let expected_positions = [
-            ('d', 5), ('d', 6), ('d', 7), ('d', 8),
-            ('d', 3), ('d', 2), ('d', 1),
-            ('e', 4), ('f', 4), ('g', 4), ('h', 4),
-            ('c', 4), ('b', 4), ('a', 4),
-        ];
-        for (file, rank) in expected_positions.iter() {
-            assert!(moves.contains(&Position::new(*file, *rank).unwrap()), "Rook move to {}{} missing.", file, rank);
+        let mut board = Board::new();
+        for mv in ["e2e4", "a7a6", "e4e5", "d7d5"] {
+            board.make_uci_move(mv).unwrap();
         }
+        assert_eq!(kind_of(&board, sq('e', 5), sq('d', 6)), MoveKind::EnPassant);
+
+        let mut board = Board::empty();
+        board.set_piece(sq('a', 1), Piece { color: Color::White, kind: PieceType::King });
+        board.set_piece(sq('h', 8), Piece { color: Color::Black, kind: PieceType::King });
+        board.set_piece(sq('b', 7), Piece { color: Color::White, kind: PieceType::Pawn });
+        board.set_piece(sq('c', 8), Piece { color: Color::Black, kind: PieceType::Rook });
+        let kinds: Vec<MoveKind> = board
+            .get_all_legal_moves_classified()
+            .into_iter()
+            .filter(|(mv, _)| mv.to == sq('c', 8))
+            .map(|(_, kind)| kind)
+            .collect();
+        let expected: Vec<MoveKind> = PieceType::promotion_choices().into_iter().map(MoveKind::Promotion).collect();
+        assert_eq!(kinds, expected);
     }
+}
 
-    #[test]
-    fn test_bishop_movement_clear() {
-        let pieces = vec![
-            ('d', 4, Color::White, PieceType::Bishop),
-        ];
-        let mut board = Board::new();
-        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+#[cfg(test)]
+mod board_eq_tests {
+    use super::*;
+    use std::collections::HashSet;
 
-        let moves = board.get_legal_moves(Position::new('d', 4).unwrap());
-        let expected_positions = [
-            ('e', 5), ('f', 6), ('g', 7), ('h', 8),
-            ('c', 5), ('b', 6), ('a', 7),
-            ('e', 3), ('f', 2), ('g', 1),
-            ('c', 3), ('b', 2), ('a', 1),
-        ];
-        for (file, rank) in expected_positions.iter() {
-            assert!(moves.contains(&Position::new(*file, *rank).unwrap()), "Bishop move to {}{} missing.", file, rank);
+    fn play(moves: &[&str]) -> Board {
+        let mut board = Board::new();
+        for mv in moves {
+            board.make_uci_move(mv).unwrap();
         }
+        board
     }
 
     #[test]
-    fn test_queen_movement_clear() {
-        let pieces = vec![
-            ('d', 4, Color::White, PieceType::Queen),
-        ];
-        let mut board = Board::new();
-        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
-
-        let moves = board.get_legal_moves(Position::new('d', 4).unwrap());
+    fn test_transposition_is_equal_and_hashes_equal() {
+        let a = play(&["g1f3", "g8f6", "b1c3"]);
+        let b = play(&["b1c3", "g8f6", "g1f3"]);
+        assert_eq!(a, b);
+        let set: HashSet<Board> = [a, b].into_iter().collect();
+        assert_eq!(set.len(), 1);
+    }
 
-        assert!(moves.contains(&Position::new('d', 5).unwrap()), "Queen vertical move missing.");
-        assert!(moves.contains(&Position::new('e', 5).unwrap()), "Queen diagonal move missing.");
-        assert!(moves.contains(&Position::new('h', 4).unwrap()), "Queen horizontal move missing.");
+    #[test]
+    fn test_counters_are_ignored() {
+        let a = Board::new();
+        let b = play(&["g1f3", "g8f6", "f3g1", "f6g8"]);
+        assert_ne!(a.halfmove_clock, b.halfmove_clock);
+        assert_eq!(a, b);
     }
 
     #[test]
-    fn test_king_movement_clear() {
-        let pieces = vec![
-            ('e', 4, Color::White, PieceType::King),
-        ];
+    fn test_castling_rights_and_turn_matter() {
+        // Same pieces, but the rooks have moved away and back
+        let a = play(&["g1f3", "g8f6", "h1g1", "h8g8", "g1h1", "g8h8"]);
+        let b = play(&["g1f3", "g8f6", "b1c3", "b8c6", "c3b1", "c6b8"]);
+        assert_eq!(a.squares, b.squares);
+        assert_ne!(a, b);
+
+        let mut c = b.clone();
+        c.turn = Color::Black;
+        assert_ne!(b, c);
+        let set: HashSet<Board> = [a, b, c].into_iter().collect();
+        assert_eq!(set.len(), 3);
+    }
+}
+
+#[cfg(test)]
+mod sorted_moves_tests {
+    use super::*;
+
+    fn play(moves: &[&str]) -> Board {
         let mut board = Board::new();
-        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        for mv in moves {
+            board.make_uci_move(mv).unwrap();
+        }
+        board
+    }
 
-        let moves = board.get_legal_moves(Position::new('e', 4).unwrap());
-        let expected_positions = [
-            ('d', 4), ('f', 4),
-            ('e', 5), ('e', 3),
-            ('d', 5), ('f', 5),
-            ('d', 3), ('f', 3),
-        ];
-        for (file, rank) in expected_positions.iter() {
-            assert!(moves.contains(&Position::new(*file, *rank).unwrap()), "King move to {}{} missing.", file, rank);
+    #[test]
+    fn test_sorted_moves_are_reproducible() {
+        let moves = ["e2e4", "d7d5", "e4d5", "g8f6", "f1b5"];
+        let a = play(&moves);
+        let b = play(&moves);
+        for color in [Color::White, Color::Black] {
+            let sorted = a.get_all_legal_moves_sorted(color);
+            assert_eq!(sorted, a.get_all_legal_moves_sorted(color));
+            assert_eq!(sorted, b.get_all_legal_moves_sorted(color));
+            assert!(sorted.windows(2).all(|pair| {
+                (pair[0].from.file, pair[0].from.rank, pair[0].to.file, pair[0].to.rank)
+                    <= (pair[1].from.file, pair[1].from.rank, pair[1].to.file, pair[1].to.rank)
+            }));
         }
+        assert_eq!(a.get_all_legal_moves_sorted(Color::Black).len(), a.get_all_legal_moves().len());
     }
 
     #[test]
-    fn test_king_castling_moves_allowed() {
+    fn test_sorted_moves_keep_promotion_order() {
+        let board = play(&["h2h4", "g7g5", "h4g5", "g8f6", "g5g6", "f6e4", "g6g7", "e4f6"]);
+        let promotions: Vec<_> = board
+            .get_all_legal_moves_sorted(Color::White)
+            .into_iter()
+            .filter(|mv| mv.to == Position::new('h', 8).unwrap())
+            .map(|mv| mv.promotion)
+            .collect();
+        let expected: Vec<_> = PieceType::promotion_choices().into_iter().map(Some).collect();
+        assert_eq!(promotions, expected);
+    }
+}
+
+#[cfg(test)]
+mod check_evasion_tests {
+    use super::*;
+
+    fn mv(uci: &str) -> Move {
+        Move::from_uci(uci).unwrap()
+    }
+
+    #[test]
+    fn test_single_rook_check_evasions() {
         let pieces = vec![
             ('e', 1, Color::White, PieceType::King),
-            ('h', 1, Color::White, PieceType::Rook),
-            ('a', 1, Color::White, PieceType::Rook),
+            ('a', 4, Color::White, PieceType::Rook),
+            ('f', 6, Color::White, PieceType::Knight),
+            ('e', 8, Color::Black, PieceType::Rook),
+            ('h', 8, Color::Black, PieceType::King),
         ];
         let mut board = Board::new();
         board.initialize_custom(pieces, Color::White, GameState::Ongoing);
 
-        let moves = board.get_legal_moves(Position::new('e', 1).unwrap());
-        assert!(moves.contains(&Position::new('g', 1).unwrap()), "Kingside castling move should be allowed.");
-        assert!(moves.contains(&Position::new('c', 1).unwrap()), "Queenside castling move should be allowed.");
+        let evasions = board.check_evasions(Color::White);
+        assert!(evasions.contains(&mv("e1d1")), "king move");
+        assert!(evasions.contains(&mv("f6e8")), "capture the checker");
+        assert!(evasions.contains(&mv("a4e4")), "interpose");
+        assert!(!evasions.contains(&mv("e1e2")), "still on the checking file");
+        assert!(!evasions.contains(&mv("a4a5")), "ignores the check");
+        for evasion in evasions {
+            let mut after = board.clone();
+            after.try_move(evasion.from, evasion.to, evasion.promotion).unwrap();
+            assert!(!after.is_in_check(Color::White));
+        }
     }
 
     #[test]
-    fn test_king_castling_blocked_path() {
+    fn test_double_check_allows_only_king_moves() {
         let pieces = vec![
             ('e', 1, Color::White, PieceType::King),
-            ('h', 1, Color::White, PieceType::Rook),
-            ('f', 1, Color::White, PieceType::Knight), // Blocking kingside
+            ('a', 4, Color::White, PieceType::Rook),
+            ('e', 8, Color::Black, PieceType::Rook),
+            ('b', 4, Color::Black, PieceType::Bishop),
+            ('h', 8, Color::Black, PieceType::King),
         ];
         let mut board = Board::new();
         board.initialize_custom(pieces, Color::White, GameState::Ongoing);
 
-        let moves = board.get_legal_moves(Position::new('e', 1).unwrap());
-        assert!(!moves.contains(&Position::new('g', 1).unwrap()), "Kingside castling should not be allowed if path is blocked.");
+        let evasions = board.check_evasions(Color::White);
+        assert!(!evasions.is_empty());
+        assert!(evasions.iter().all(|evasion| evasion.from == Position::new('e', 1).unwrap()));
     }
 
     #[test]
-    fn test_get_legal_moves_empty_square() {
-        let pieces = vec![];
-        let mut board = Board::new();
-        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
-
-        let moves = board.get_legal_moves(Position::new('e', 4).unwrap());
-        assert!(moves.is_empty(), "No moves should exist for empty square.");
+    fn test_no_evasions_when_not_in_check() {
+        let board = Board::new();
+        assert!(board.check_evasions(Color::White).is_empty());
     }
 }
 
 #[cfg(test)]
-mod en_passant_tests {
+mod line_pieces_tests {
     use super::*;
 
     #[test]
-    fn test_en_passant_available() {
-        let pieces = vec![
-            ('e', 5, Color::White, PieceType::Pawn),
-            ('d', 7, Color::Black, PieceType::Pawn),
-            ('e', 8, Color::Black, PieceType::King),
-            ('a', 8, Color::Black, PieceType::Rook),
-        ];
+    fn test_piece_counts_at_start_and_after_capture() {
         let mut board = Board::new();
-        board.initialize_custom(pieces, Color::Black, GameState::Ongoing);
+        for color in [Color::White, Color::Black] {
+            let counts = board.piece_counts(color);
+            assert_eq!(counts.len(), 6);
+            assert_eq!(counts[&PieceType::Pawn], 8);
+            assert_eq!(counts[&PieceType::Rook], 2);
+            assert_eq!(counts[&PieceType::Knight], 2);
+            assert_eq!(counts[&PieceType::Bishop], 2);
+            assert_eq!(counts[&PieceType::Queen], 1);
+            assert_eq!(counts[&PieceType::King], 1);
+        }
 
-        // Black plays d7-d5
-        board.try_move(Position::new('d', 7).unwrap(), Position::new('d', 5).unwrap(), None).unwrap();
+        for mv in ["e2e4", "d7d5", "e4d5", "d8d5"] {
+            board.make_uci_move(mv).unwrap();
+        }
+        assert_eq!(board.piece_counts(Color::White)[&PieceType::Pawn], 7);
+        assert_eq!(board.piece_counts(Color::Black)[&PieceType::Pawn], 7);
+    }
 
-        // Now white can en passant capture at d6
-        let moves = board.get_legal_moves(Position::new('e', 5).unwrap());
-        assert!(moves.contains(&Position::new('d', 6).unwrap()), "En passant should be available");
+    #[test]
+    fn test_rank_pieces_back_rank_in_file_order() {
+        let board = Board::new();
+        let back_rank = board.rank_pieces(1);
+        let kinds: Vec<PieceType> = back_rank.iter().map(|(_, piece)| piece.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                PieceType::Rook,
+                PieceType::Knight,
+                PieceType::Bishop,
+                PieceType::Queen,
+                PieceType::King,
+                PieceType::Bishop,
+                PieceType::Knight,
+                PieceType::Rook,
+            ]
+        );
+        let files: String = back_rank.iter().map(|(pos, _)| pos.file).collect();
+        assert_eq!(files, "abcdefgh");
+        assert!(back_rank.iter().all(|(pos, piece)| pos.rank == 1 && piece.color == Color::White));
+        assert!(board.rank_pieces(0).is_empty());
     }
 
     #[test]
-    fn test_en_passant_capture_execution() {
-        let pieces = vec![
-            ('e', 5, Color::White, PieceType::Pawn),
-            ('d', 7, Color::Black, PieceType::Pawn),
-            ('e', 8, Color::Black, PieceType::King),
-            ('a', 8, Color::Black, PieceType::Rook),
-        ];
+    fn test_file_pieces_finds_doubled_pawns() {
         let mut board = Board::new();
-        board.initialize_custom(pieces, Color::Black, GameState::Ongoing);
-
-        // Black plays d7-d5
-        board.try_move(Position::new('d', 7).unwrap(), Position::new('d', 5).unwrap(), None).unwrap();
-
-        // White plays e5xd6 en passant
-        board.try_move(Position::new('e', 5).unwrap(), Position::new('d', 6).unwrap(), None).unwrap();
-
-        assert!(board.squares.get(&Position::new('d', 5).unwrap()).is_none(), "Captured pawn should be gone after en passant.");
-        assert_eq!(board.squares.get(&Position::new('d', 6).unwrap()).unwrap().kind, PieceType::Pawn);
+        for mv in ["e2e4", "d7d5", "e4d5"] {
+            board.make_uci_move(mv).unwrap();
+        }
+        let d_file: Vec<(u8, Piece)> = board.file_pieces('d').into_iter().map(|(pos, piece)| (pos.rank, piece)).collect();
+        let white_pawn = Piece { color: Color::White, kind: PieceType::Pawn };
+        assert_eq!(d_file.len(), 4);
+        assert_eq!(d_file[1], (2, white_pawn));
+        assert_eq!(d_file[2], (5, white_pawn));
+        assert_eq!(d_file.iter().filter(|(_, piece)| *piece == white_pawn).count(), 2);
+        assert!(board.file_pieces('z').is_empty());
     }
 }
 
 #[cfg(test)]
-mod stalemate_tests {
+mod pawn_structure_tests {
     use super::*;
 
     #[test]
-    fn test_simple_stalemate_position() {
+    fn test_doubled_and_isolated_pawns() {
         let pieces = vec![
-            ('h', 1, Color::White, PieceType::King),
-            ('f', 2, Color::Black, PieceType::Queen),
-            ('g', 3, Color::Black, PieceType::King),
+            ('e', 1, Color::White, PieceType::King),
+            ('e', 8, Color::Black, PieceType::King),
+            // Doubled c-pawns, supported by the b-pawn
+            ('b', 2, Color::White, PieceType::Pawn),
+            ('c', 2, Color::White, PieceType::Pawn),
+            ('c', 3, Color::White, PieceType::Pawn),
+            // A lone h-pawn
+            ('h', 2, Color::White, PieceType::Pawn),
+            // Black pawns blocking every white pawn
+            ('b', 7, Color::Black, PieceType::Pawn),
+            ('g', 7, Color::Black, PieceType::Pawn),
         ];
         let mut board = Board::new();
         board.initialize_custom(pieces, Color::White, GameState::Ongoing);
 
-        assert!(board.is_stalemate(Color::White), "White should be stalemated in this position.");
+        let white = board.pawn_structure(Color::White);
+        assert_eq!(white, PawnStructure { doubled: 1, isolated: 1, passed: 0 });
+        let black = board.pawn_structure(Color::Black);
+        assert_eq!(black, PawnStructure { doubled: 0, isolated: 2, passed: 0 });
     }
 
     #[test]
-    fn test_not_stalemate_king_can_move() {
+    fn test_passed_pawn() {
         let pieces = vec![
-            ('h', 1, Color::White, PieceType::King),
-            ('f', 1, Color::Black, PieceType::Queen),
-            ('g', 2, Color::Black, PieceType::King),
+            ('e', 1, Color::White, PieceType::King),
+            ('e', 8, Color::Black, PieceType::King),
+            ('d', 5, Color::White, PieceType::Pawn),
+            ('e', 4, Color::Black, PieceType::Pawn),
+            ('a', 2, Color::White, PieceType::Pawn),
+            ('b', 3, Color::Black, PieceType::Pawn),
         ];
         let mut board = Board::new();
         board.initialize_custom(pieces, Color::White, GameState::Ongoing);
 
-        assert!(!board.is_stalemate(Color::White), "White should not be stalemated (king has legal moves).");
+        // d5 and e4 have run past each other, so both are passed; a2 and b3 still guard each other's path
+        assert_eq!(board.pawn_structure(Color::White).passed, 1);
+        assert_eq!(board.pawn_structure(Color::Black).passed, 1);
     }
+}
 
-    #[test]
-    fn test_stalemate_with_multiple_pieces_on_board() {
-        let pieces = vec![
-            ('a', 1, Color::White, PieceType::King),
-            ('c', 2, Color::Black, PieceType::Queen),
-            ('b', 4, Color::Black, PieceType::Bishop),
-            ('b', 3, Color::White, PieceType::Pawn),
-        ];
-        let mut board = Board::new();
-        board.initialize_custom(pieces, Color::White, GameState::Ongoing);
-
-        assert!(board.is_stalemate(Color::White), "White should be stalemated even with other pieces on board.");
-    }
+#[cfg(test)]
+mod validate_position_tests {
+    use super::*;
 
     #[test]
-    fn test_not_stalemate_in_check() {
+    fn test_reports_extra_king_and_back_rank_pawn() {
         let pieces = vec![
-            ('a', 1, Color::White, PieceType::King),
-            ('c', 3, Color::Black, PieceType::Queen),
-            ('b', 4, Color::Black, PieceType::Bishop),
-            ('b', 3, Color::White, PieceType::Pawn),
+            ('e', 1, Color::White, PieceType::King),
+            ('a', 3, Color::White, PieceType::King),
+            ('c', 8, Color::White, PieceType::Pawn),
+            ('g', 8, Color::Black, PieceType::King),
         ];
         let mut board = Board::new();
         board.initialize_custom(pieces, Color::White, GameState::Ongoing);
-
-        assert!(!board.is_stalemate(Color::White), "White is in check, so not stalemate.");
+        let problems = board.validate_position().unwrap_err();
+        assert_eq!(problems.len(), 2, "{problems:?}");
+        assert!(problems[0].contains("White has 2 kings"));
+        assert!(problems[1].contains("c8"));
     }
 
     #[test]
-    fn test_no_legal_moves_but_in_checkmate_not_stalemate() {
+    fn test_reports_check_on_waiting_side_and_stale_castling_rights() {
         let pieces = vec![
-            ('a', 1, Color::White, PieceType::King),
-            ('b', 2, Color::Black, PieceType::Queen),
-            ('c', 3, Color::Black, PieceType::Bishop),
+            ('e', 1, Color::White, PieceType::King),
+            ('h', 1, Color::White, PieceType::Rook),
+            ('e', 8, Color::Black, PieceType::King),
+            ('e', 4, Color::White, PieceType::Queen),
         ];
         let mut board = Board::new();
         board.initialize_custom(pieces, Color::White, GameState::Ongoing);
+        board.set_castling_rights(true, true, false, false);
+        let problems = board.validate_position().unwrap_err();
+        assert_eq!(problems.len(), 2, "{problems:?}");
+        assert!(problems[0].contains("Black is in check"));
+        assert!(problems[1].contains("White may castle queenside"));
 
-        assert!(!board.is_stalemate(Color::White), "White is in checkmate, not stalemate.");
-        assert!(board.is_checkmate(Color::White), "White is in checkmate, not stalemate.");
+        board.set_castling_rights(true, false, false, false);
+        board.turn = Color::Black;
+        assert_eq!(board.validate_position(), Ok(()));
     }
 
     #[test]
-    fn test_black_stalemated_simple() {
-        let pieces = vec![
-            ('a', 8, Color::Black, PieceType::King),
-            ('c', 7, Color::White, PieceType::Queen),
-            ('b', 6, Color::White, PieceType::King),
-        ];
-        let mut board = Board::new();
-        board.initialize_custom(pieces, Color::Black, GameState::Ongoing);
-
-        assert!(board.is_stalemate(Color::Black), "Black should be stalemated.");
+    fn test_sees_pieces_placed_with_set_piece() {
+        let mut board = Board::empty();
+        board.set_piece(Position::new('e', 1).unwrap(), Piece { color: Color::White, kind: PieceType::King });
+        board.set_piece(Position::new('e', 8).unwrap(), Piece { color: Color::Black, kind: PieceType::King });
+        board.set_piece(Position::new('e', 4).unwrap(), Piece { color: Color::White, kind: PieceType::Queen });
+        let problems = board.validate_position().unwrap_err();
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].contains("Black is in check"));
     }
 }
-
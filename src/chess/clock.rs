@@ -0,0 +1,139 @@
+use super::piece::Color;
+use std::time::{Duration, Instant};
+
+/// A chess clock with a time allowance per side and a per-move increment.
+///
+/// Call [`Clock::start_turn`] when a side begins thinking and [`Clock::stop_turn`] once it has
+/// moved; the time spent is deducted from that side and the increment added back.
+///
+/// # Examples
+/// ```
+/// use puzzle_engine::chess::clock::Clock;
+/// use puzzle_engine::chess::Color;
+/// use std::time::Duration;
+///
+/// let mut clock = Clock::new(Duration::from_secs(300), Duration::from_secs(2));
+/// clock.start_turn(Color::White);
+/// clock.stop_turn();
+/// assert!(!clock.flagged(Color::White));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Clock {
+    // Remaining time for White and Black, not counting a turn in progress.
+    remaining: [Duration; 2],
+    increment: Duration,
+    // The side whose clock is running and when its turn started.
+    running: Option<(Color, Instant)>,
+}
+
+impl Clock {
+    /// Creates a stopped clock giving both sides `initial` time and `increment` per move.
+    #[must_use]
+    pub fn new(initial: Duration, increment: Duration) -> Self {
+        Self {
+            remaining: [initial; 2],
+            increment,
+            running: None,
+        }
+    }
+
+    /// Starts `color`'s clock, stopping the other side's first if it was running.
+    pub fn start_turn(&mut self, color: Color) {
+        self.start_turn_at(color, Instant::now());
+    }
+
+    /// Stops the running clock, deducting the time used and adding the increment unless the
+    /// side has run out of time. Returns the time the turn took, or `None` if no clock was
+    /// running.
+    pub fn stop_turn(&mut self) -> Option<Duration> {
+        self.stop_turn_at(Instant::now())
+    }
+
+    /// Returns the time `color` has left, including any turn in progress.
+    #[must_use]
+    pub fn remaining(&self, color: Color) -> Duration {
+        self.remaining_at(color, Instant::now())
+    }
+
+    /// Returns `true` if `color` has run out of time.
+    #[must_use]
+    pub fn flagged(&self, color: Color) -> bool {
+        self.remaining(color).is_zero()
+    }
+
+    /// Returns the side whose clock is running, if any.
+    #[must_use]
+    pub fn running(&self) -> Option<Color> {
+        self.running.map(|(color, _)| color)
+    }
+
+    fn start_turn_at(&mut self, color: Color, now: Instant) {
+        self.stop_turn_at(now);
+        self.running = Some((color, now));
+    }
+
+    fn stop_turn_at(&mut self, now: Instant) -> Option<Duration> {
+        let (color, started) = self.running.take()?;
+        let elapsed = now.saturating_duration_since(started);
+        let remaining = &mut self.remaining[Self::index(color)];
+        *remaining = remaining.saturating_sub(elapsed);
+        if !remaining.is_zero() {
+            *remaining += self.increment;
+        }
+        Some(elapsed)
+    }
+
+    fn remaining_at(&self, color: Color, now: Instant) -> Duration {
+        let remaining = self.remaining[Self::index(color)];
+        match self.running {
+            Some((running, started)) if running == color => {
+                remaining.saturating_sub(now.saturating_duration_since(started))
+            }
+            _ => remaining,
+        }
+    }
+
+    fn index(color: Color) -> usize {
+        match color {
+            Color::White => 0,
+            Color::Black => 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `Duration::from_mins` would need a much newer toolchain than the rest of the crate
+    #[allow(clippy::duration_suboptimal_units)]
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_increment_added_after_move() {
+        let mut clock = Clock::new(MINUTE, Duration::from_secs(5));
+        let start = Instant::now();
+        clock.start_turn_at(Color::White, start);
+        assert_eq!(clock.running(), Some(Color::White));
+        assert_eq!(clock.stop_turn_at(start + Duration::from_secs(10)), Some(Duration::from_secs(10)));
+        assert_eq!(clock.remaining_at(Color::White, start), Duration::from_secs(55));
+        assert_eq!(clock.remaining_at(Color::Black, start), MINUTE);
+        assert_eq!(clock.stop_turn(), None);
+    }
+
+    #[test]
+    fn test_running_out_of_time_flags_that_side() {
+        let mut clock = Clock::new(MINUTE, Duration::from_secs(5));
+        let start = Instant::now();
+        clock.start_turn_at(Color::White, start);
+        clock.start_turn_at(Color::Black, start + Duration::from_secs(1));
+        assert_eq!(clock.running(), Some(Color::Black));
+        assert_eq!(clock.remaining_at(Color::Black, start + Duration::from_secs(31)), Duration::from_secs(30));
+
+        clock.stop_turn_at(start + Duration::from_secs(90));
+        assert!(clock.flagged(Color::Black));
+        assert!(!clock.flagged(Color::White));
+        // No increment once the flag has fallen
+        assert_eq!(clock.remaining(Color::Black), Duration::ZERO);
+    }
+}
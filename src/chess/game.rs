@@ -0,0 +1,200 @@
+pub use super::board::{Board, Outcome};
+pub use super::moves::Move;
+use super::piece::{Color, Piece, PieceType};
+
+/// A chess game: a [`Board`] together with the record of moves played on it.
+///
+/// `Board` only knows the current position; `Game` keeps every move so the game can be
+/// reviewed, exported or taken back.
+#[derive(Debug, Clone)]
+pub struct Game {
+    pub board: Board,
+    moves: Vec<Move>,
+    // The piece taken by each move, if any.
+    captures: Vec<Option<Piece>>,
+    // The board before each move, for undo.
+    history: Vec<Board>,
+}
+
+impl Game {
+    /// Creates a new game from the standard starting position.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::chess::game::Game;
+    /// let game = Game::new();
+    /// assert!(game.moves().is_empty());
+    /// assert_eq!(game.result(), None);
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            board: Board::new(),
+            moves: Vec::new(),
+            captures: Vec::new(),
+            history: Vec::new(),
+        }
+    }
+
+    /// Plays a move and records it.
+    ///
+    /// # Errors
+    /// Returns the error from [`Board::try_move`] if the move is illegal; the game is unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::chess::game::{Game, Move};
+    /// let mut game = Game::new();
+    /// game.play(Move::from_uci("e2e4").unwrap()).unwrap();
+    /// assert!(game.play(Move::from_uci("e2e4").unwrap()).is_err());
+    /// assert_eq!(game.moves().len(), 1);
+    /// ```
+    pub fn play(&mut self, mv: Move) -> Result<(), String> {
+        let before = self.board.clone();
+        self.board.try_move(mv.from, mv.to, mv.promotion)?;
+        self.captures.push(captured_by(&before, mv));
+        self.history.push(before);
+        self.moves.push(mv);
+        Ok(())
+    }
+
+    /// Takes back the last move, returning it.
+    ///
+    /// # Errors
+    /// Returns an error if no moves have been played.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::chess::game::{Game, Move};
+    /// use puzzle_engine::chess::Color;
+    /// let mut game = Game::new();
+    /// let e4 = Move::from_uci("e2e4").unwrap();
+    /// game.play(e4).unwrap();
+    /// assert_eq!(game.undo(), Ok(e4));
+    /// assert_eq!(game.board.turn, Color::White);
+    /// ```
+    pub fn undo(&mut self) -> Result<Move, String> {
+        let (Some(board), Some(mv)) = (self.history.pop(), self.moves.pop()) else {
+            return Err("No moves to undo.".to_string());
+        };
+        self.board = board;
+        self.captures.pop();
+        Ok(mv)
+    }
+
+    /// Returns the moves played so far, in order.
+    #[must_use]
+    pub fn moves(&self) -> &[Move] {
+        &self.moves
+    }
+
+    /// Returns the kinds of the pieces of `color` captured so far, in the order they were taken.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::chess::game::{Game, Move};
+    /// use puzzle_engine::chess::{Color, PieceType};
+    /// let mut game = Game::new();
+    /// for mv in ["e2e4", "d7d5", "e4d5"] {
+    ///     game.play(Move::from_uci(mv).unwrap()).unwrap();
+    /// }
+    /// assert_eq!(game.captured_pieces(Color::Black), vec![PieceType::Pawn]);
+    /// assert!(game.captured_pieces(Color::White).is_empty());
+    /// ```
+    #[must_use]
+    pub fn captured_pieces(&self, color: Color) -> Vec<PieceType> {
+        self.captures
+            .iter()
+            .flatten()
+            .filter(|piece| piece.color == color)
+            .map(|piece| piece.kind)
+            .collect()
+    }
+
+    /// Returns the result of the game, or `None` while it is still being played.
+    ///
+    /// See [`Board::game_result`].
+    #[must_use]
+    pub fn result(&self) -> Option<Outcome> {
+        self.board.game_result()
+    }
+}
+
+/// The piece `mv` takes when played on `board`, including a pawn captured en passant.
+fn captured_by(board: &Board, mv: Move) -> Option<Piece> {
+    let mover = board.squares().get(&mv.from)?;
+    match board.squares().get(&mv.to) {
+        // A king moving onto its own rook is castling, not a capture
+        Some(target) if target.color != mover.color => Some(*target),
+        None if mover.kind == PieceType::Pawn && board.en_passant_target == Some(mv.to) => {
+            Some(Piece { color: mover.color.opponent(), kind: PieceType::Pawn })
+        }
+        _ => None,
+    }
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uci(mv: &str) -> Move {
+        Move::from_uci(mv).unwrap()
+    }
+
+    #[test]
+    fn test_fools_mate_is_recorded() {
+        let mut game = Game::new();
+        let moves = ["f2f3", "e7e5", "g2g4", "d8h4"].map(uci);
+        for mv in moves {
+            game.play(mv).unwrap();
+        }
+        assert_eq!(game.moves(), moves.as_slice());
+        assert_eq!(game.result(), Some(Outcome::BlackWins));
+        assert!(game.play(uci("e2e4")).is_err());
+        assert_eq!(game.moves().len(), 4);
+    }
+
+    #[test]
+    fn test_undo_restores_previous_position() {
+        let mut game = Game::new();
+        game.play(uci("e2e4")).unwrap();
+        let after_e4 = game.board.to_string();
+        game.play(uci("e7e5")).unwrap();
+
+        assert_eq!(game.undo(), Ok(uci("e7e5")));
+        assert_eq!(game.board.to_string(), after_e4);
+        assert_eq!(game.moves(), &[uci("e2e4")]);
+        assert_eq!(game.undo(), Ok(uci("e2e4")));
+        assert_eq!(game.undo(), Err("No moves to undo.".to_string()));
+    }
+
+    #[test]
+    fn test_captured_pieces_follow_play_and_undo() {
+        let mut game = Game::new();
+        for mv in ["e2e4", "d7d5", "e4d5", "d8d5"] {
+            game.play(uci(mv)).unwrap();
+        }
+        assert_eq!(game.captured_pieces(Color::White), vec![PieceType::Pawn], "The queen took the d5 pawn.");
+        assert_eq!(game.captured_pieces(Color::Black), vec![PieceType::Pawn]);
+
+        game.undo().unwrap();
+        assert!(game.captured_pieces(Color::White).is_empty());
+        assert_eq!(game.captured_pieces(Color::Black), vec![PieceType::Pawn]);
+    }
+
+    #[test]
+    fn test_en_passant_is_recorded_as_pawn_capture() {
+        let mut game = Game::new();
+        for mv in ["e2e4", "a7a6", "e4e5", "d7d5", "e5d6"] {
+            game.play(uci(mv)).unwrap();
+        }
+        assert_eq!(game.captured_pieces(Color::Black), vec![PieceType::Pawn]);
+        assert!(game.captured_pieces(Color::White).is_empty());
+    }
+}
@@ -10,14 +10,26 @@
 //!
 //! # Modules
 //! - `board` — Board representation and game logic.
+//! - `ai` — A minimax move picker.
 //! - `piece` — Piece definitions and types.
 //! - `position` — Board position handling.
+//! - `moves` — Move representation.
+//! - `clock` — Chess clocks with increment.
+//! - `game` — A board together with its move record.
+//! - `pgn` — PGN movetext import and export.
 //! 
+pub mod ai;
 pub mod board;
+pub mod clock;
+pub mod game;
+pub mod moves;
+pub mod pgn;
 pub mod piece;
 pub mod position;
 
 pub use board::Board;
+pub use game::Game;
+pub use moves::{Move, MoveKind};
 pub use position::Position;
 pub use piece::{Color, PieceType, Piece};
-pub use board::GameState;
+pub use board::{GameState, DisplayOptions, DrawPolicy, DrawReason, Outcome, PawnStructure};
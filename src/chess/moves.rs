@@ -0,0 +1,114 @@
+use super::piece::PieceType;
+use super::position::Position;
+use std::fmt;
+
+/// A single chess move from one square to another, with an optional promotion piece.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Move {
+    pub from: Position,
+    pub to: Position,
+    pub promotion: Option<PieceType>,
+}
+
+/// What a move does, for move ordering and filtering.
+///
+/// See [`Board::get_all_legal_moves_classified`](super::Board::get_all_legal_moves_classified).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MoveKind {
+    /// A move to an empty square, including pawn pushes.
+    Quiet,
+    /// A capture of the given piece type on the destination square.
+    Capture(PieceType),
+    /// A pawn capturing en passant.
+    EnPassant,
+    /// Castling on either side.
+    Castle,
+    /// A pawn promoting to the given piece type, whether or not it also captures.
+    Promotion(PieceType),
+}
+
+impl Move {
+    /// Creates a new move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mv = Move::new(Position::new('e', 2).unwrap(), Position::new('e', 4).unwrap(), None);
+    /// assert_eq!(mv.to_string(), "e2e4");
+    /// ```
+    #[must_use]
+    pub fn new(from: Position, to: Position, promotion: Option<PieceType>) -> Self {
+        Self { from, to, promotion }
+    }
+
+    /// Parses a move in long algebraic (UCI) notation, such as `e2e4` or `e7e8q`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not four or five characters long, names a square
+    /// off the board, or uses a promotion letter other than `q`, `r`, `b` or `n`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::*;
+    ///
+    /// let mv = Move::from_uci("a7a8q").unwrap();
+    /// assert_eq!(mv.promotion, Some(PieceType::Queen));
+    /// assert!(Move::from_uci("e2e9").is_err());
+    /// ```
+    pub fn from_uci(uci: &str) -> Result<Self, String> {
+        let chars: Vec<char> = uci.chars().collect();
+        if chars.len() != 4 && chars.len() != 5 {
+            return Err(format!("Invalid UCI move '{uci}': expected 4 or 5 characters."));
+        }
+        let square = |file: char, rank: char| {
+            rank.to_digit(10)
+                .and_then(|rank| u8::try_from(rank).ok())
+                .and_then(|rank| Position::new(file, rank))
+                .ok_or_else(|| format!("Invalid UCI move '{uci}': bad square '{file}{rank}'."))
+        };
+        let from = square(chars[0], chars[1])?;
+        let to = square(chars[2], chars[3])?;
+        let promotion = match chars.get(4) {
+            None => None,
+            Some('q') => Some(PieceType::Queen),
+            Some('r') => Some(PieceType::Rook),
+            Some('b') => Some(PieceType::Bishop),
+            Some('n') => Some(PieceType::Knight),
+            Some(other) => return Err(format!("Invalid UCI move '{uci}': unknown promotion '{other}'.")),
+        };
+        Ok(Self { from, to, promotion })
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.from, self.to)?;
+        if let Some(kind) = self.promotion {
+            write!(f, "{}", kind.to_char())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_uci_round_trips_display() {
+        for uci in ["e2e4", "g1f3", "a7a8q", "h2h1n"] {
+            assert_eq!(Move::from_uci(uci).unwrap().to_string(), uci);
+        }
+    }
+
+    #[test]
+    fn test_from_uci_rejects_malformed() {
+        for uci in ["", "e2", "e2e", "e2e9", "i2i4", "e2e4qq", "a7a8k", "a7a8x"] {
+            assert!(Move::from_uci(uci).is_err(), "'{uci}' should be rejected");
+        }
+    }
+}
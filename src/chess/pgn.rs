@@ -0,0 +1,152 @@
+use super::board::Board;
+use super::moves::Move;
+use super::piece::Color;
+
+const RESULT_TOKENS: [&str; 4] = ["1-0", "0-1", "1/2-1/2", "*"];
+
+/// Parses the movetext of a PGN game into moves played from the standard starting position.
+///
+/// Tag pairs (`[Event "..."]`), comments in braces or after `;`, numeric annotation glyphs
+/// (`$1`) and move numbers are skipped. Each remaining token is resolved as SAN against the
+/// position reached so far. Parsing stops at the result token (`1-0`, `0-1`, `1/2-1/2` or `*`).
+///
+/// # Errors
+///
+/// Returns an error if a comment is left unterminated or a token is not a legal SAN move.
+///
+/// # Examples
+///
+/// ```
+/// use puzzle_engine::chess::pgn::parse_pgn;
+///
+/// let moves = parse_pgn("1. e4 {best by test} e5 2. Nf3 $1 1-0").unwrap();
+/// assert_eq!(moves.len(), 3);
+/// ```
+pub fn parse_pgn(pgn: &str) -> Result<Vec<Move>, String> {
+    let mut board = Board::new();
+    let mut moves = Vec::new();
+    for token in tokenize(pgn)? {
+        if RESULT_TOKENS.contains(&token.as_str()) {
+            break;
+        }
+        let san = strip_move_number(&token);
+        if san.is_empty() || san.starts_with('$') {
+            continue;
+        }
+        let mv = board.parse_san(san)?;
+        board.try_move(mv.from, mv.to, mv.promotion)?;
+        moves.push(mv);
+    }
+    Ok(moves)
+}
+
+/// Writes moves played from the standard starting position as PGN movetext, such as
+/// `1. e4 e5 2. Nf3`.
+///
+/// # Errors
+///
+/// Returns an error if any move is illegal in the position it is played from.
+///
+/// # Examples
+///
+/// ```
+/// use puzzle_engine::chess::*;
+/// use puzzle_engine::chess::pgn::write_movetext;
+///
+/// let moves = [Move::from_uci("e2e4").unwrap(), Move::from_uci("e7e5").unwrap()];
+/// assert_eq!(write_movetext(&moves).unwrap(), "1. e4 e5");
+/// ```
+pub fn write_movetext(moves: &[Move]) -> Result<String, String> {
+    let mut board = Board::new();
+    let mut parts = Vec::new();
+    for mv in moves {
+        if board.turn == Color::White {
+            parts.push(format!("{}.", board.fullmove_number));
+        }
+        parts.push(board.move_to_san(mv)?);
+        board.try_move(mv.from, mv.to, mv.promotion)?;
+    }
+    Ok(parts.join(" "))
+}
+
+/// Strips a leading move number such as `4.` or `4...`, leaving tokens like `0-0` intact.
+fn strip_move_number(token: &str) -> &str {
+    let rest = token.trim_start_matches(|c: char| c.is_ascii_digit());
+    if rest.len() < token.len() && rest.starts_with('.') {
+        rest.trim_start_matches('.')
+    } else {
+        token
+    }
+}
+
+/// Splits movetext into whitespace-separated tokens, dropping tag pairs and comments.
+fn tokenize(pgn: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = pgn.chars();
+    while let Some(c) = chars.next() {
+        let skip_until = match c {
+            '{' => Some('}'),
+            '[' => Some(']'),
+            ';' => Some('\n'),
+            _ => None,
+        };
+        if c.is_whitespace() || skip_until.is_some() {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(c);
+        }
+        if let Some(end) = skip_until {
+            if !chars.any(|c| c == end) && end != '\n' {
+                return Err(format!("Unterminated PGN comment or tag: expected '{end}'."));
+            }
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_then_export_round_trips() {
+        let pgn = r#"[Event "Casual"]
+[White "A"]
+[Black "B"]
+
+1. e4 e5 2. Nf3 Nc6 {the usual} 3. Bb5 a6 $1 4. Ba4 Nf6 5. O-O Be7 ; Closed Ruy Lopez
+6. Re1 b5 7. Bb3 d6 1/2-1/2"#;
+        let moves = parse_pgn(pgn).unwrap();
+        assert_eq!(moves.len(), 14);
+        assert_eq!(
+            write_movetext(&moves).unwrap(),
+            "1. e4 e5 2. Nf3 Nc6 3. Bb5 a6 4. Ba4 Nf6 5. O-O Be7 6. Re1 b5 7. Bb3 d6"
+        );
+    }
+
+    #[test]
+    fn test_parse_stops_at_result() {
+        let moves = parse_pgn("1.e4 e5 0-1 2. Nf3").unwrap();
+        assert_eq!(moves.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_digit_zero_castling() {
+        let moves = parse_pgn("1. e4 e5 2. Nf3 Nc6 3. Bc4 Bc5 4. 0-0").unwrap();
+        assert_eq!(moves.len(), 7);
+        assert_eq!(moves[6], Move::from_uci("e1g1").unwrap());
+        assert_eq!(parse_pgn("1.e4 e5 2.Nf3 Nc6 3.Bc4 Bc5 4.0-0 4...Nf6").unwrap().len(), 8);
+    }
+
+    #[test]
+    fn test_parse_rejects_illegal_san() {
+        assert!(parse_pgn("1. e5").is_err());
+        assert!(parse_pgn("1. e4 {unterminated").is_err());
+    }
+}
@@ -1,14 +1,32 @@
 use std::fmt;
 
 /// The color of a chess piece.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Color {
     White,
     Black,
 }
 
+impl Color {
+    /// Returns the opposing color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::Color;
+    /// assert_eq!(Color::White.opponent(), Color::Black);
+    /// ```
+    #[must_use]
+    pub fn opponent(self) -> Color {
+        match self {
+            Color::White => Color::Black,
+            Color::Black => Color::White,
+        }
+    }
+}
+
 /// The type of a chess piece.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum PieceType {
     Pawn,
     Rook,
@@ -18,8 +36,105 @@ pub enum PieceType {
     King,
 }
 
+impl PieceType {
+    /// Returns the conventional material value of the piece (P=1, N=3, B=3, R=5, Q=9, K=0).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::PieceType;
+    /// assert_eq!(PieceType::Rook.value(), 5);
+    /// ```
+    #[must_use]
+    pub fn value(&self) -> u32 {
+        match self {
+            PieceType::Pawn => 1,
+            PieceType::Knight | PieceType::Bishop => 3,
+            PieceType::Rook => 5,
+            PieceType::Queen => 9,
+            PieceType::King => 0,
+        }
+    }
+
+    /// Returns the lowercase letter used for the piece in FEN and UCI notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::PieceType;
+    /// assert_eq!(PieceType::Knight.to_char(), 'n');
+    /// ```
+    #[must_use]
+    pub fn to_char(&self) -> char {
+        match self {
+            PieceType::Pawn => 'p',
+            PieceType::Rook => 'r',
+            PieceType::Knight => 'n',
+            PieceType::Bishop => 'b',
+            PieceType::Queen => 'q',
+            PieceType::King => 'k',
+        }
+    }
+
+    /// Returns the piece types a pawn may promote to, strongest first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::PieceType;
+    /// assert!(!PieceType::promotion_choices().contains(&PieceType::King));
+    /// ```
+    #[must_use]
+    pub fn promotion_choices() -> [PieceType; 4] {
+        [PieceType::Queen, PieceType::Rook, PieceType::Bishop, PieceType::Knight]
+    }
+
+    /// Returns every piece type, from pawn to king in declaration order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::PieceType;
+    /// let total: u32 = PieceType::all().iter().map(PieceType::value).sum();
+    /// assert_eq!(total, 21);
+    /// ```
+    #[must_use]
+    pub fn all() -> [PieceType; 6] {
+        [
+            PieceType::Pawn,
+            PieceType::Rook,
+            PieceType::Knight,
+            PieceType::Bishop,
+            PieceType::Queen,
+            PieceType::King,
+        ]
+    }
+
+    /// Parses a piece letter, accepting either case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::PieceType;
+    /// assert_eq!(PieceType::from_char('Q'), Some(PieceType::Queen));
+    /// assert_eq!(PieceType::from_char('x'), None);
+    /// ```
+    #[must_use]
+    pub fn from_char(c: char) -> Option<PieceType> {
+        match c.to_ascii_lowercase() {
+            'p' => Some(PieceType::Pawn),
+            'r' => Some(PieceType::Rook),
+            'n' => Some(PieceType::Knight),
+            'b' => Some(PieceType::Bishop),
+            'q' => Some(PieceType::Queen),
+            'k' => Some(PieceType::King),
+            _ => None,
+        }
+    }
+}
+
 /// A chess piece with type and color.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Piece {
     pub color: Color,
     pub kind: PieceType,
@@ -29,4 +144,40 @@ impl fmt::Display for Piece {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?} {:?}", self.color, self.kind)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_piece_type_char_round_trip() {
+        for kind in PieceType::all() {
+            assert_eq!(PieceType::from_char(kind.to_char()), Some(kind));
+            assert_eq!(PieceType::from_char(kind.to_char().to_ascii_uppercase()), Some(kind));
+        }
+        assert_eq!(PieceType::from_char('z'), None);
+    }
+
+    #[test]
+    fn test_piece_type_all_lists_each_variant_once() {
+        let all = PieceType::all();
+        assert_eq!(all.len(), 6);
+        let unique: std::collections::HashSet<_> = all.iter().collect();
+        assert_eq!(unique.len(), 6);
+        for kind in [PieceType::Pawn, PieceType::Rook, PieceType::Knight, PieceType::Bishop, PieceType::Queen, PieceType::King] {
+            assert_eq!(all.iter().filter(|&&k| k == kind).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_color_opponent() {
+        assert_eq!(Color::White.opponent(), Color::Black);
+        assert_eq!(Color::Black.opponent(), Color::White);
+    }
+
+    #[test]
+    fn test_piece_type_values() {
+        let values: Vec<u32> = PieceType::all().iter().map(PieceType::value).collect();
+        assert_eq!(values, vec![1, 5, 3, 3, 9, 0]);
+    }
+}
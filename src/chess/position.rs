@@ -16,10 +16,139 @@ impl Position {
             None
         }
     }
+
+    /// Returns all 64 squares, rank by rank from a1, b1, ... h1 up to h8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::Position;
+    /// assert_eq!(Position::all().count(), 64);
+    /// assert_eq!(Position::all().nth(8), Position::new('a', 2));
+    /// ```
+    pub fn all() -> impl Iterator<Item = Position> {
+        (1..=8).flat_map(|rank| ('a'..='h').map(move |file| Position { file, rank }))
+    }
+
+    /// Returns the square `df` files and `dr` ranks away, or `None` if it is off the board.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::Position;
+    /// let e4 = Position::new('e', 4).unwrap();
+    /// assert_eq!(e4.offset(1, 2), Position::new('f', 6));
+    /// assert_eq!(e4.offset(4, 0), None);
+    /// ```
+    #[must_use]
+    pub fn offset(&self, df: i8, dr: i8) -> Option<Position> {
+        let file = (self.file as u8).checked_add_signed(df)? as char;
+        let rank = self.rank.checked_add_signed(dr)?;
+        Position::new(file, rank)
+    }
+
+    /// Returns `true` if this is a light square. a1 is dark, so h1 and a8 are light.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::Position;
+    /// assert!(!Position::new('a', 1).unwrap().is_light());
+    /// assert!(Position::new('h', 1).unwrap().is_light());
+    /// ```
+    #[must_use]
+    pub fn is_light(&self) -> bool {
+        (self.file as u8 - b'a' + self.rank).is_multiple_of(2)
+    }
+
+    /// Returns the squares strictly between `a` and `b`, in order from `a`.
+    ///
+    /// The squares must share a rank, file or diagonal; otherwise the result is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use puzzle_engine::chess::Position;
+    /// let between = Position::iter_between(Position::new('a', 1).unwrap(), Position::new('a', 4).unwrap());
+    /// assert_eq!(between, vec![Position::new('a', 2).unwrap(), Position::new('a', 3).unwrap()]);
+    /// ```
+    #[must_use]
+    pub fn iter_between(a: Position, b: Position) -> Vec<Position> {
+        let df = i16::from(b.file as u8) - i16::from(a.file as u8);
+        let dr = i16::from(b.rank) - i16::from(a.rank);
+        if (df == 0 && dr == 0) || (df != 0 && dr != 0 && df.abs() != dr.abs()) {
+            return Vec::new();
+        }
+        // Both steps are -1, 0 or 1, so the casts cannot truncate
+        let step = (df.signum() as i8, dr.signum() as i8);
+        let mut squares = Vec::new();
+        let mut current = a;
+        while let Some(next) = current.offset(step.0, step.1) {
+            if next == b {
+                break;
+            }
+            squares.push(next);
+            current = next;
+        }
+        squares
+    }
 }
 
 impl fmt::Display for Position {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}{}", self.file, self.rank)
     }
-}
\ No newline at end of file
+}
+
+/// Shorthand for a square known to be valid, shared by the chess test modules.
+#[cfg(test)]
+pub(crate) fn sq(file: char, rank: u8) -> Position {
+    Position::new(file, rank).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_light_square_colors() {
+        assert!(!sq('a', 1).is_light());
+        assert!(!sq('h', 8).is_light());
+        assert!(sq('a', 8).is_light());
+        assert!(sq('h', 1).is_light());
+        assert_eq!(Position::all().filter(Position::is_light).count(), 32);
+    }
+
+    #[test]
+    fn test_all_yields_each_square_once() {
+        let squares: Vec<Position> = Position::all().collect();
+        assert_eq!(squares.len(), 64);
+        assert_eq!(squares.first(), Some(&sq('a', 1)));
+        assert_eq!(squares.last(), Some(&sq('h', 8)));
+        let unique: std::collections::HashSet<_> = squares.iter().collect();
+        assert_eq!(unique.len(), 64);
+        assert!(squares.iter().all(|pos| Position::new(pos.file, pos.rank) == Some(*pos)));
+    }
+
+    #[test]
+    fn test_offset_stops_at_board_edge() {
+        assert_eq!(sq('a', 1).offset(-1, 0), None);
+        assert_eq!(sq('a', 1).offset(0, -1), None);
+        assert_eq!(sq('h', 8).offset(1, 1), None);
+        assert_eq!(sq('h', 8).offset(-7, -7), Some(sq('a', 1)));
+        assert_eq!(sq('d', 4).offset(0, 0), Some(sq('d', 4)));
+    }
+
+    #[test]
+    fn test_iter_between_excludes_endpoints() {
+        assert_eq!(Position::iter_between(sq('a', 4), sq('e', 4)), vec![sq('b', 4), sq('c', 4), sq('d', 4)]);
+        assert_eq!(Position::iter_between(sq('h', 8), sq('e', 5)), vec![sq('g', 7), sq('f', 6)]);
+        assert!(Position::iter_between(sq('d', 4), sq('d', 5)).is_empty());
+    }
+
+    #[test]
+    fn test_iter_between_requires_shared_line() {
+        assert!(Position::iter_between(sq('a', 1), sq('b', 3)).is_empty());
+        assert!(Position::iter_between(sq('c', 3), sq('c', 3)).is_empty());
+    }
+}
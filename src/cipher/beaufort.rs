@@ -0,0 +1,79 @@
+pub use super::traits::CipherPuzzle;
+use super::util::Alphabet;
+use super::vigenere_cipher::vigenere_transform;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Beaufort Cipher
+///
+/// Each letter is replaced by subtracting it from the corresponding keyword letter,
+/// `cipher = (key - plain) mod 26`. The cipher is reciprocal: encrypting and decrypting
+/// are the same operation.
+///
+/// ## Example
+/// ```rust
+/// use puzzle_engine::cipher::beaufort::{Beaufort, CipherPuzzle};
+/// let b = Beaufort::new("KEY");
+/// let encrypted = b.encrypt("HELLO");
+/// assert_eq!(encrypted, "DANZQ");
+/// assert_eq!(b.encrypt(&encrypted), "HELLO");
+/// ```
+pub struct Beaufort {
+    keyword: Vec<u8>, // letter shifts
+}
+
+impl Beaufort {
+    /// Create a new Beaufort cipher from a keyword (A-Z only)
+    #[must_use]
+    pub fn new(keyword: &str) -> Self {
+        let keyword = keyword
+            .chars()
+            .filter(char::is_ascii_alphabetic)
+            .map(|c| c.to_ascii_uppercase() as u8 - b'A')
+            .collect();
+        Self { keyword }
+    }
+
+    /// Encrypt lazily, yielding one ciphertext character at a time.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use puzzle_engine::cipher::prelude::*;
+    /// let b = Beaufort::new("KEY");
+    /// assert_eq!(b.encrypt_iter("HELLO").collect::<String>(), b.encrypt("HELLO"));
+    /// ```
+    pub fn encrypt_iter<'a>(&'a self, plaintext: &'a str) -> impl Iterator<Item = char> + 'a {
+        vigenere_transform(plaintext, &self.keyword, &Alphabet::STANDARD, |offset, key| (26 + key - offset) % 26)
+    }
+}
+
+impl CipherPuzzle for Beaufort {
+    fn encrypt(&self, plaintext: &str) -> String {
+        self.encrypt_iter(plaintext).collect()
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> String {
+        self.encrypt(ciphertext)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn beaufort_matches_hand_computed_example() {
+        let b = Beaufort::new("KEY");
+        assert_eq!(b.encrypt("HELLO"), "DANZQ");
+        assert_eq!(b.encrypt("Hello, World!"), "Danzq, Cwnnh!");
+    }
+
+    #[test]
+    fn beaufort_is_reciprocal() {
+        let b = Beaufort::new("fortification");
+        let plain = "Defend the east wall of the castle!";
+        let encrypted = b.encrypt(plain);
+        assert_eq!(b.encrypt(&encrypted), plain);
+        assert_eq!(b.decrypt(&encrypted), plain);
+    }
+}
@@ -1,6 +1,7 @@
 
 pub use super::traits::CipherPuzzle;
-use super::util::shift_char;
+use super::util::Alphabet;
+use alloc::string::String;
 
 /// Caesar Cipher
 ///
@@ -8,34 +9,105 @@ use super::util::shift_char;
 ///
 /// ## Example
 /// ```rust
-/// use puzzle_engine::cipher::caesar_cipher::{Caesar, CipherPuzzle};
+/// use puzzle_engine::cipher::prelude::*;
 /// let c = Caesar::new(1);
 /// assert_eq!(c.encrypt("ABC"), "BCD");
 /// assert_eq!(c.decrypt("BCD"), "ABC");
 /// ```
 pub struct Caesar {
     shift: u8,
+    alphabet: Alphabet,
+    // Shift applied to the digits 0-9, if they are rotated at all
+    digit_shift: Option<u8>,
 }
 
 impl Caesar {
     /// Create a new Caesar cipher with the given shift (0-25)
     pub fn new(shift: u8) -> Self {
-        Self { shift: shift % 26 }
+        Self { shift: shift % 26, alphabet: Alphabet::STANDARD, digit_shift: None }
+    }
+
+    /// Create a Caesar cipher that also rotates the digits 0-9 by `shift % 10`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use puzzle_engine::cipher::prelude::*;
+    /// let c = Caesar::new_with_digits(3);
+    /// assert_eq!(c.encrypt("Room 789"), "Urrp 012");
+    /// assert_eq!(c.decrypt("Urrp 012"), "Room 789");
+    /// ```
+    #[must_use]
+    pub fn new_with_digits(shift: u8) -> Self {
+        Self { digit_shift: Some(shift % 10), ..Self::new(shift) }
+    }
+
+    /// Create a Caesar cipher that shifts letters along a keyed alphabet instead of A-Z.
+    ///
+    /// Each letter is found in `alphabet` and replaced by the letter `shift` places after it,
+    /// wrapping around. Case is preserved and non-letters pass through unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error unless `alphabet` contains each letter A-Z exactly once (in either case).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use puzzle_engine::cipher::prelude::*;
+    /// let alphabet: Vec<char> = "ZEBRASCDFGHIJKLMNOPQTUVWXY".chars().collect();
+    /// let c = Caesar::with_alphabet(1, alphabet.try_into().unwrap()).unwrap();
+    /// assert_eq!(c.encrypt("Zebra!"), "Ebras!");
+    /// assert_eq!(c.decrypt("Ebras!"), "Zebra!");
+    /// ```
+    pub fn with_alphabet(shift: u8, alphabet: [char; 26]) -> Result<Self, String> {
+        Ok(Self { alphabet: Alphabet::new(alphabet)?, ..Self::new(shift) })
+    }
+
+    /// The letter shift, normalized to 0-25.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use puzzle_engine::cipher::prelude::*;
+    /// assert_eq!(Caesar::new(30).shift(), 4);
+    /// ```
+    #[must_use]
+    pub fn shift(&self) -> u8 {
+        self.shift
+    }
+
+    /// Encrypt lazily, yielding one ciphertext character at a time.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use puzzle_engine::cipher::prelude::*;
+    /// let c = Caesar::new(1);
+    /// assert_eq!(c.encrypt_iter("ABC").collect::<String>(), "BCD");
+    /// ```
+    pub fn encrypt_iter<'a>(&'a self, plaintext: &'a str) -> impl Iterator<Item = char> + 'a {
+        plaintext
+            .chars()
+            .map(|c| self.shift_char(c, self.shift, self.digit_shift.unwrap_or(0)))
+    }
+
+    /// Shift a letter by `letter_shift` and, if enabled, a digit by `digit_shift`.
+    fn shift_char(&self, c: char, letter_shift: u8, digit_shift: u8) -> char {
+        match c.to_digit(10) {
+            Some(digit) if self.digit_shift.is_some() => {
+                char::from_digit((digit + u32::from(digit_shift)) % 10, 10).unwrap_or(c)
+            }
+            _ => self.alphabet.shift(c, letter_shift),
+        }
     }
 }
 
 impl CipherPuzzle for Caesar {
     fn encrypt(&self, plaintext: &str) -> String {
-        plaintext
-            .chars()
-            .map(|c| shift_char(c, self.shift))
-            .collect()
+        self.encrypt_iter(plaintext).collect()
     }
 
     fn decrypt(&self, ciphertext: &str) -> String {
         ciphertext
             .chars()
-            .map(|c| shift_char(c, 26 - self.shift))
+            .map(|c| self.shift_char(c, 26 - self.shift, 10 - self.digit_shift.unwrap_or(0)))
             .collect()
     }
 }
@@ -47,9 +119,16 @@ impl CipherPuzzle for Caesar {
 mod tests {
     use super::*;
 
+    #[test]
+    fn caesar_shift_is_normalized() {
+        assert_eq!(Caesar::new(30).shift(), 4);
+        assert_eq!(Caesar::new(3).shift(), 3);
+        assert_eq!(Caesar::new(26).shift(), 0);
+    }
+
     #[test]
     fn caesar_encrypts_correctly() {
-        let c = Caesar { shift: 3 };
+        let c = Caesar::new(3);
         let plain = "Hello, World!";
         let expected = "Khoor, Zruog!";
         let encrypted = c.encrypt(plain);
@@ -58,7 +137,7 @@ mod tests {
     
     #[test]
     fn caesar_upper_with_wrap_encrypts_correctly() {
-        let c = Caesar { shift: 3 };
+        let c = Caesar::new(3);
         let plain = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
         let expected = "DEFGHIJKLMNOPQRSTUVWXYZABC";
         let encrypted = c.encrypt(plain);
@@ -67,7 +146,7 @@ mod tests {
 
     #[test]
     fn caesar_lower_with_wrap_encrypts_correctly() {
-        let c = Caesar { shift: 3 };
+        let c = Caesar::new(3);
         let plain = "abcdefghijklmnopqrstuvwxyz";
         let expected = "defghijklmnopqrstuvwxyzabc";
         let encrypted = c.encrypt(plain);
@@ -76,10 +155,61 @@ mod tests {
 
     #[test]
     fn caesar_encrypt_decrypt() {
-        let c = Caesar { shift: 3 };
+        let c = Caesar::new(3);
         let plain = "Hello, World!";
         let encrypted = c.encrypt(plain);
         let decrypted = c.decrypt(&encrypted);
         assert_eq!(decrypted, plain);
     }
+
+    const SCRAMBLED: &str = "QWERTYUIOPASDFGHJKLZXCVBNM";
+
+    fn alphabet(letters: &str) -> [char; 26] {
+        letters.chars().collect::<Vec<_>>().try_into().unwrap()
+    }
+
+    #[test]
+    fn caesar_identity_alphabet_matches_standard() {
+        let standard = Caesar::new(7);
+        let keyed = Caesar::with_alphabet(7, alphabet("abcdefghijklmnopqrstuvwxyz")).unwrap();
+        let plain = "Hello, World! xyz";
+        assert_eq!(keyed.encrypt(plain), standard.encrypt(plain));
+        assert_eq!(keyed.decrypt(plain), standard.decrypt(plain));
+    }
+
+    #[test]
+    fn caesar_scrambled_alphabet_is_invertible() {
+        let standard = Caesar::new(3);
+        let keyed = Caesar::with_alphabet(3, alphabet(SCRAMBLED)).unwrap();
+        let plain = "The quick brown fox jumps over the lazy dog.";
+        let encrypted = keyed.encrypt(plain);
+        assert_ne!(encrypted, standard.encrypt(plain));
+        assert_eq!(&encrypted[..3], "Ily");
+        assert_eq!(keyed.decrypt(&encrypted), plain);
+    }
+
+    #[test]
+    fn caesar_rejects_invalid_alphabet() {
+        let repeated = alphabet("AACDEFGHIJKLMNOPQRSTUVWXYZ");
+        assert!(Caesar::with_alphabet(1, repeated).is_err());
+        let mut digit = alphabet(SCRAMBLED);
+        digit[0] = '1';
+        assert!(Caesar::with_alphabet(1, digit).is_err());
+    }
+
+    #[test]
+    fn caesar_with_digits_rotates_digits() {
+        let c = Caesar::new_with_digits(3);
+        assert_eq!(c.encrypt("ABC123"), "DEF456");
+        assert_eq!(c.encrypt("xyz789"), "abc012");
+        assert_eq!(c.decrypt("DEF456"), "ABC123");
+        assert_eq!(Caesar::new(3).encrypt("ABC123"), "DEF123");
+    }
+
+    #[test]
+    fn caesar_encrypt_iter_matches_encrypt() {
+        let c = Caesar::new_with_digits(5);
+        let plain = "Meet at 10, by the old oak.";
+        assert_eq!(c.encrypt_iter(plain).collect::<String>(), c.encrypt(plain));
+    }
 }
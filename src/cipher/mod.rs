@@ -1,5 +1,10 @@
+pub mod beaufort;
 pub mod caesar_cipher;
+pub mod morse;
+pub mod pipeline;
+pub mod playfair;
+pub mod prelude;
 pub mod vigenere_cipher;
 pub mod traits;
-mod util;
+pub mod util;
 
@@ -0,0 +1,91 @@
+//! Morse Code
+//!
+//! Letters and digits are written as dots and dashes. Symbols within a word are separated
+//! by a space and words by ` / `.
+//!
+//! ## Example
+//! ```rust
+//! use puzzle_engine::cipher::morse;
+//! let encoded = morse::encode("SOS");
+//! assert_eq!(encoded, "... --- ...");
+//! assert_eq!(morse::decode(&encoded).unwrap(), "SOS");
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Letters and digits with their Morse sequences.
+const TABLE: [(char, &str); 36] = [
+    ('A', ".-"), ('B', "-..."), ('C', "-.-."), ('D', "-.."), ('E', "."), ('F', "..-."),
+    ('G', "--."), ('H', "...."), ('I', ".."), ('J', ".---"), ('K', "-.-"), ('L', ".-.."),
+    ('M', "--"), ('N', "-."), ('O', "---"), ('P', ".--."), ('Q', "--.-"), ('R', ".-."),
+    ('S', "..."), ('T', "-"), ('U', "..-"), ('V', "...-"), ('W', ".--"), ('X', "-..-"),
+    ('Y', "-.--"), ('Z', "--.."), ('0', "-----"), ('1', ".----"), ('2', "..---"),
+    ('3', "...--"), ('4', "....-"), ('5', "....."), ('6', "-...."), ('7', "--..."),
+    ('8', "---.."), ('9', "----."),
+];
+
+/// Encode text as Morse code
+///
+/// Letters are uppercased first; characters with no Morse equivalent are skipped.
+#[must_use]
+pub fn encode(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter_map(|c| {
+                    let c = c.to_ascii_uppercase();
+                    TABLE.iter().find(|(letter, _)| *letter == c).map(|(_, code)| *code)
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// Decode Morse code back into uppercase text
+///
+/// # Errors
+///
+/// Returns an error naming the first sequence that is not a known letter or digit.
+pub fn decode(morse: &str) -> Result<String, String> {
+    let mut words = Vec::new();
+    for word in morse.split('/') {
+        let mut decoded = String::new();
+        for symbol in word.split_whitespace() {
+            let (letter, _) = TABLE
+                .iter()
+                .find(|(_, code)| *code == symbol)
+                .ok_or_else(|| format!("Unknown Morse sequence '{symbol}'"))?;
+            decoded.push(*letter);
+        }
+        words.push(decoded);
+    }
+    Ok(words.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn morse_round_trip() {
+        let encoded = encode("SOS HELP");
+        assert_eq!(encoded, "... --- ... / .... . .-.. .--.");
+        assert_eq!(decode(&encoded).unwrap(), "SOS HELP");
+    }
+
+    #[test]
+    fn morse_encode_uppercases_and_skips_unknown() {
+        assert_eq!(encode("sos!"), "... --- ...");
+        assert_eq!(encode("r2d2"), ".-. ..--- -.. ..---");
+    }
+
+    #[test]
+    fn morse_decode_rejects_unknown_sequence() {
+        assert!(decode("........").is_err());
+    }
+}
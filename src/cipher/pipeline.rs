@@ -0,0 +1,98 @@
+pub use super::traits::CipherPuzzle;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Cipher Pipeline
+///
+/// Chains several ciphers into one. Encryption runs the stages front to back, and
+/// decryption undoes them back to front.
+///
+/// ## Example
+/// ```rust
+/// use puzzle_engine::cipher::pipeline::Pipeline;
+/// use puzzle_engine::cipher::prelude::*;
+/// let p = Pipeline::new().then(Caesar::new(3)).then(Vigenere::new("KEY"));
+/// let encrypted = p.encrypt("Attack at dawn!");
+/// assert_eq!(p.decrypt(&encrypted), "Attack at dawn!");
+/// ```
+#[derive(Default)]
+pub struct Pipeline {
+    stages: Vec<Box<dyn CipherPuzzle>>,
+}
+
+impl Pipeline {
+    /// Create an empty pipeline, which leaves text unchanged
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a cipher as the last stage of the pipeline
+    #[must_use]
+    pub fn then(mut self, cipher: impl CipherPuzzle + 'static) -> Self {
+        self.stages.push(Box::new(cipher));
+        self
+    }
+
+    /// Number of ciphers in the pipeline
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Whether the pipeline has no ciphers
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+}
+
+impl From<Vec<Box<dyn CipherPuzzle>>> for Pipeline {
+    fn from(stages: Vec<Box<dyn CipherPuzzle>>) -> Self {
+        Self { stages }
+    }
+}
+
+impl CipherPuzzle for Pipeline {
+    fn encrypt(&self, plaintext: &str) -> String {
+        self.stages
+            .iter()
+            .fold(String::from(plaintext), |text, stage| stage.encrypt(&text))
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> String {
+        self.stages
+            .iter()
+            .rev()
+            .fold(String::from(ciphertext), |text, stage| stage.decrypt(&text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::caesar_cipher::Caesar;
+    use crate::cipher::vigenere_cipher::Vigenere;
+
+    #[test]
+    fn pipeline_applies_stages_in_order() {
+        let p = Pipeline::new().then(Caesar::new(3)).then(Vigenere::new("KEY"));
+        let plain = "Meet me at noon!";
+        let expected = Vigenere::new("KEY").encrypt(&Caesar::new(3).encrypt(plain));
+        let encrypted = p.encrypt(plain);
+        assert_eq!(encrypted, expected);
+        assert_eq!(p.decrypt(&encrypted), plain);
+        assert!(p.validate_solution(&encrypted, "MEET ME AT NOON!"));
+    }
+
+    #[test]
+    fn pipeline_from_boxed_stages() {
+        let stages: Vec<Box<dyn CipherPuzzle>> = vec![Box::new(Caesar::new(1)), Box::new(Caesar::new(2))];
+        let p = Pipeline::from(stages);
+        assert_eq!(p.len(), 2);
+        assert_eq!(p.encrypt("abc"), "def");
+        assert!(Pipeline::new().is_empty());
+        assert_eq!(Pipeline::new().encrypt("abc"), "abc");
+    }
+}
@@ -0,0 +1,141 @@
+pub use super::traits::CipherPuzzle;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Playfair Cipher
+///
+/// Letters are encrypted in pairs using a 5x5 key square built from a keyword, with I and J
+/// sharing a cell. Pairs in the same row shift right, pairs in the same column shift down, and
+/// any other pair swaps columns across the rectangle it forms.
+///
+/// Only letters are kept and the output is uppercase. Before encrypting, an `X` is inserted
+/// between doubled letters in a pair and odd-length input is padded with `X` (`Q` is used
+/// instead when the letter itself is `X`).
+///
+/// ## Example
+/// ```rust
+/// use puzzle_engine::cipher::playfair::{Playfair, CipherPuzzle};
+/// let p = Playfair::new("PLAYFAIR EXAMPLE");
+/// let encrypted = p.encrypt("hide the gold");
+/// assert_eq!(encrypted, "BMODZBXDNAGE");
+/// assert_eq!(p.decrypt(&encrypted), "HIDETHEGOLDX");
+/// ```
+pub struct Playfair {
+    square: [u8; 25], // key square letters, row by row
+}
+
+impl Playfair {
+    /// Create a new Playfair cipher from a keyword (A-Z only)
+    #[must_use]
+    pub fn new(keyword: &str) -> Self {
+        let mut square = [0; 25];
+        let mut len = 0;
+        for letter in normalize(keyword).into_iter().chain(b'A'..=b'Z') {
+            if letter != b'J' && !square[..len].contains(&letter) {
+                square[len] = letter;
+                len += 1;
+            }
+        }
+        Self { square }
+    }
+
+    /// Row and column of a letter in the key square
+    fn locate(&self, letter: u8) -> (usize, usize) {
+        let index = self.square.iter().position(|&l| l == letter).unwrap_or(0);
+        (index / 5, index % 5)
+    }
+
+    /// Encrypt (`step` = 1) or decrypt (`step` = 4) prepared digraphs
+    fn transform(&self, letters: &[u8], step: usize) -> String {
+        let mut result = String::with_capacity(letters.len());
+        for pair in letters.chunks(2) {
+            let (r1, c1) = self.locate(pair[0]);
+            let (r2, c2) = self.locate(pair[1]);
+            let (a, b) = if r1 == r2 {
+                ((r1, (c1 + step) % 5), (r2, (c2 + step) % 5))
+            } else if c1 == c2 {
+                (((r1 + step) % 5, c1), ((r2 + step) % 5, c2))
+            } else {
+                ((r1, c2), (r2, c1))
+            };
+            result.push(self.square[a.0 * 5 + a.1] as char);
+            result.push(self.square[b.0 * 5 + b.1] as char);
+        }
+        result
+    }
+}
+
+impl CipherPuzzle for Playfair {
+    fn encrypt(&self, plaintext: &str) -> String {
+        let mut letters = Vec::new();
+        let mut pending = normalize(plaintext).into_iter().peekable();
+        while let Some(first) = pending.next() {
+            let filler = if first == b'X' { b'Q' } else { b'X' };
+            let second = match pending.peek() {
+                Some(&next) if next != first => {
+                    pending.next();
+                    next
+                }
+                _ => filler,
+            };
+            letters.push(first);
+            letters.push(second);
+        }
+        self.transform(&letters, 1)
+    }
+
+    fn decrypt(&self, ciphertext: &str) -> String {
+        let mut letters = normalize(ciphertext);
+        if letters.len() % 2 == 1 {
+            letters.push(b'X');
+        }
+        self.transform(&letters, 4)
+    }
+}
+
+/// Uppercase letters only, with J folded into I
+fn normalize(text: &str) -> Vec<u8> {
+    text.bytes()
+        .filter(u8::is_ascii_alphabetic)
+        .map(|b| match b.to_ascii_uppercase() {
+            b'J' => b'I',
+            upper => upper,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn playfair_textbook_example() {
+        let p = Playfair::new("PLAYFAIR EXAMPLE");
+        let encrypted = p.encrypt("Hide the gold in the tree stump");
+        assert_eq!(encrypted, "BMODZBXDNABEKUDMUIXMMOUVIF");
+        assert_eq!(p.decrypt(&encrypted), "HIDETHEGOLDINTHETREXESTUMP");
+
+        let p = Playfair::new("PLAYFAIR");
+        let encrypted = p.encrypt("Hide the gold in the tree stump");
+        assert_eq!(encrypted, "EBIMQMGHVRIRONKGODKUKNNZEF");
+        assert_eq!(p.decrypt(&encrypted), "HIDETHEGOLDINTHETREXESTUMP");
+    }
+
+    #[test]
+    fn playfair_pads_odd_input_and_merges_j() {
+        let p = Playfair::new("keyword");
+        let encrypted = p.encrypt("jam");
+        assert_eq!(encrypted.len(), 4);
+        assert_eq!(p.decrypt(&encrypted), "IAMX");
+    }
+
+    #[test]
+    fn playfair_key_square_has_each_letter_once() {
+        let p = Playfair::new("Playfair Example");
+        let mut letters = p.square.to_vec();
+        letters.sort_unstable();
+        letters.dedup();
+        assert_eq!(letters.len(), 25);
+        assert!(!letters.contains(&b'J'));
+    }
+}
@@ -0,0 +1,14 @@
+//! Convenient imports for working with ciphers.
+//!
+//! ```rust
+//! use puzzle_engine::cipher::prelude::*;
+//! let v = Vigenere::new("KEY");
+//! assert_eq!(v.decrypt(&v.encrypt("Attack at dawn!")), "Attack at dawn!");
+//! ```
+
+pub use super::beaufort::Beaufort;
+pub use super::caesar_cipher::Caesar;
+pub use super::pipeline::Pipeline;
+pub use super::playfair::Playfair;
+pub use super::traits::CipherPuzzle;
+pub use super::vigenere_cipher::Vigenere;
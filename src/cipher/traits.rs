@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 /// Trait for common cipher operations
 pub trait CipherPuzzle {
     /// Encrypt the given plaintext
@@ -6,8 +8,29 @@ pub trait CipherPuzzle {
     /// Decrypt the given ciphertext
     fn decrypt(&self, ciphertext: &str) -> String;
 
-    /// Check whether a guess correctly decrypts the ciphertext
+    /// Check whether a guess correctly decrypts the ciphertext, ignoring ASCII case
     fn validate_solution(&self, ciphertext: &str, guess: &str) -> bool {
         self.decrypt(ciphertext).eq_ignore_ascii_case(guess)
     }
+
+    /// Check whether a guess decrypts the ciphertext exactly, including case
+    fn validate_solution_exact(&self, ciphertext: &str, guess: &str) -> bool {
+        self.decrypt(ciphertext) == guess
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::caesar_cipher::Caesar;
+
+    #[test]
+    fn exact_validation_is_case_sensitive() {
+        let c = Caesar::new(3);
+        let ciphertext = c.encrypt("Hello");
+        assert!(c.validate_solution(&ciphertext, "HELLO"));
+        assert!(!c.validate_solution_exact(&ciphertext, "HELLO"));
+        assert!(c.validate_solution_exact(&ciphertext, "Hello"));
+        assert!(!c.validate_solution(&ciphertext, "Help"));
+    }
 }
\ No newline at end of file
@@ -1,11 +1,116 @@
-/// Shift a single character by `amount`, wrapping A-Z.
-/// Non-alphabetic characters are returned unchanged.
-pub fn shift_char(c: char, amount: u8) -> char {
-    if c.is_ascii_uppercase() {
-        (((c as u8 - b'A' + amount) % 26) + b'A') as char
-    } else if c.is_ascii_lowercase() {
-        (((c as u8 - b'a' + amount) % 26) + b'a') as char
-    } else {
-        c
-    }
-}
\ No newline at end of file
+use alloc::format;
+use alloc::string::String;
+
+/// Counts how often each letter A-Z occurs in `text`, ignoring case and any non-letters.
+///
+/// Index 0 holds the count for `A` and index 25 the count for `Z`.
+///
+/// ## Example
+/// ```rust
+/// use puzzle_engine::cipher::util::letter_frequencies;
+/// let counts = letter_frequencies("Hello, World!");
+/// assert_eq!(counts[usize::from(b'L' - b'A')], 3);
+/// ```
+#[must_use]
+pub fn letter_frequencies(text: &str) -> [usize; 26] {
+    let mut counts = [0; 26];
+    for letter in text.chars().filter_map(Alphabet::index) {
+        counts[usize::from(letter)] += 1;
+    }
+    counts
+}
+
+/// An ordering of the 26 letters that text is substituted through before shifting.
+///
+/// The standard alphabet runs A-Z; keyed alphabets scramble it. Shifting a letter moves it
+/// along this ordering, keeping its case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Alphabet {
+    // Uppercase letter at each position
+    letters: [u8; 26],
+    // Position of each letter A-Z
+    positions: [u8; 26],
+}
+
+impl Alphabet {
+    /// The plain A-Z alphabet.
+    pub(crate) const STANDARD: Alphabet = Alphabet::standard();
+
+    const fn standard() -> Self {
+        let mut letters = [0; 26];
+        let mut positions = [0; 26];
+        let mut i = 0;
+        while i < 26 {
+            letters[i as usize] = b'A' + i;
+            positions[i as usize] = i;
+            i += 1;
+        }
+        Self { letters, positions }
+    }
+
+    /// Builds a keyed alphabet, which must contain each letter exactly once, in either case.
+    pub(crate) fn new(alphabet: [char; 26]) -> Result<Self, String> {
+        let mut letters = [0; 26];
+        let mut positions = [u8::MAX; 26];
+        for (position, c) in (0u8..).zip(alphabet) {
+            let Some(letter) = Self::index(c) else {
+                return Err(format!("Alphabet contains non-letter '{c}'"));
+            };
+            if positions[usize::from(letter)] != u8::MAX {
+                return Err(format!("Alphabet repeats the letter '{c}'"));
+            }
+            positions[usize::from(letter)] = position;
+            letters[usize::from(position)] = b'A' + letter;
+        }
+        Ok(Self { letters, positions })
+    }
+
+    /// Index of an ASCII letter from 0 (A) to 25 (Z), or `None` for any other character.
+    fn index(c: char) -> Option<u8> {
+        u8::try_from(c)
+            .ok()
+            .filter(u8::is_ascii_alphabetic)
+            .map(|b| b.to_ascii_uppercase() - b'A')
+    }
+
+    /// Position of a letter in this alphabet, or `None` for any other character.
+    pub(crate) fn position(&self, c: char) -> Option<u8> {
+        Self::index(c).map(|letter| self.positions[usize::from(letter)])
+    }
+
+    /// The letter at `position` (modulo 26), in the same case as `like`.
+    pub(crate) fn letter(&self, position: u8, like: char) -> char {
+        let letter = char::from(self.letters[usize::from(position % 26)]);
+        if like.is_ascii_lowercase() { letter.to_ascii_lowercase() } else { letter }
+    }
+
+    /// Shift a single character `amount` places along the alphabet, wrapping around.
+    /// Non-alphabetic characters are returned unchanged.
+    pub(crate) fn shift(&self, c: char, amount: u8) -> char {
+        match self.position(c) {
+            Some(position) => self.letter(position + amount % 26, c),
+            None => c,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letter_frequencies_counts_case_insensitively() {
+        let counts = letter_frequencies("Banana BAND!");
+        assert_eq!(counts[0], 4);
+        assert_eq!(counts[1], 2);
+        assert_eq!(counts[3], 1);
+        assert_eq!(counts[13], 3);
+        assert_eq!(counts.iter().sum::<usize>(), 10);
+    }
+
+    #[test]
+    fn letter_frequencies_ignores_non_letters() {
+        assert_eq!(letter_frequencies("123 !? éß"), [0; 26]);
+        assert_eq!(letter_frequencies(""), [0; 26]);
+    }
+}
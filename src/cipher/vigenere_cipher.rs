@@ -1,4 +1,7 @@
 pub use super::traits::CipherPuzzle;
+use super::util::Alphabet;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 /// Vigenère Cipher
 ///
@@ -14,6 +17,7 @@ pub use super::traits::CipherPuzzle;
 /// ```
 pub struct Vigenere {
     keyword: Vec<u8>, // letter shifts
+    alphabet: Alphabet,
 }
 
 impl Vigenere {
@@ -24,46 +28,92 @@ impl Vigenere {
             .filter(|c| c.is_ascii_alphabetic())
             .map(|c| c.to_ascii_uppercase() as u8 - b'A')
             .collect();
-        Self { keyword }
+        Self { keyword, alphabet: Alphabet::STANDARD }
+    }
+
+    /// Create a Vigenère cipher over a keyed alphabet instead of A-Z.
+    ///
+    /// Letters are shifted along `alphabet`, and each keyword letter shifts by its own
+    /// position in `alphabet`. Case is preserved and non-letters pass through unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error unless `alphabet` contains each letter A-Z exactly once (in either case).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use puzzle_engine::cipher::prelude::*;
+    /// let alphabet: Vec<char> = "KRYPTOSABCDEFGHIJLMNQUVWXZ".chars().collect();
+    /// let v = Vigenere::with_alphabet("KEY", alphabet.try_into().unwrap()).unwrap();
+    /// let encrypted = v.encrypt("Attack at dawn!");
+    /// assert_eq!(v.decrypt(&encrypted), "Attack at dawn!");
+    /// ```
+    pub fn with_alphabet(keyword: &str, alphabet: [char; 26]) -> Result<Self, String> {
+        let alphabet = Alphabet::new(alphabet)?;
+        let keyword = keyword.chars().filter_map(|c| alphabet.position(c)).collect();
+        Ok(Self { keyword, alphabet })
+    }
+
+    /// The keyword as uppercase letters of this cipher's alphabet, with any characters that
+    /// were not letters dropped.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use puzzle_engine::cipher::prelude::*;
+    /// assert_eq!(Vigenere::new("Key!").keyword(), "KEY");
+    /// ```
+    #[must_use]
+    pub fn keyword(&self) -> String {
+        self.keyword.iter().map(|&shift| self.alphabet.letter(shift, 'A')).collect()
+    }
+
+    /// Encrypt lazily, yielding one ciphertext character at a time.
+    ///
+    /// The key advances only on letters, exactly as in [`CipherPuzzle::encrypt`].
+    ///
+    /// ## Example
+    /// ```rust
+    /// use puzzle_engine::cipher::prelude::*;
+    /// let v = Vigenere::new("KEY");
+    /// let first: String = v.encrypt_iter("Attack at dawn!").take(6).collect();
+    /// assert_eq!(first, "Kxrkgi");
+    /// ```
+    pub fn encrypt_iter<'a>(&'a self, plaintext: &'a str) -> impl Iterator<Item = char> + 'a {
+        vigenere_transform(plaintext, &self.keyword, &self.alphabet, |offset, key| (offset + key) % 26)
     }
 }
 
 impl CipherPuzzle for Vigenere {
     fn encrypt(&self, plaintext: &str) -> String {
-        vigenere_transform(plaintext, &self.keyword, false)
+        self.encrypt_iter(plaintext).collect()
     }
 
     fn decrypt(&self, ciphertext: &str) -> String {
-        vigenere_transform(ciphertext, &self.keyword, true)
+        vigenere_transform(ciphertext, &self.keyword, &self.alphabet, |offset, key| (26 + offset - key) % 26).collect()
     }
 }
 
 
 
-/// Core Vigenère transformation
-fn vigenere_transform(text: &str, keyword: &[u8], decrypt: bool) -> String {
-    let mut result = String::new();
+/// Core Vigenère transformation, yielding output characters lazily
+///
+/// `combine` maps a letter's position in `alphabet` (0-25) and the current key shift to the
+/// output position. Non-letters are copied unchanged and do not advance the key.
+pub(super) fn vigenere_transform<'a>(
+    text: &'a str,
+    keyword: &'a [u8],
+    alphabet: &'a Alphabet,
+    combine: impl Fn(u8, u8) -> u8 + 'a,
+) -> impl Iterator<Item = char> + 'a {
     let mut key_index = 0;
-
-    for c in text.chars() {
-        if c.is_ascii_alphabetic() {
-            let is_upper = c.is_uppercase();
-            let base = if is_upper { b'A' } else { b'a' };
-            let offset = c as u8 - base;
+    text.chars().map(move |c| match alphabet.position(c) {
+        Some(offset) => {
             let key = keyword[key_index % keyword.len()];
-            let shift = if decrypt {
-                (26 + offset - key) % 26
-            } else {
-                (offset + key) % 26
-            };
-            result.push((base + shift) as char);
             key_index += 1;
-        } else {
-            result.push(c);
+            alphabet.letter(combine(offset, key), c)
         }
-    }
-
-    result
+        None => c,
+    })
 }
 
 #[cfg(test)]
@@ -104,4 +154,45 @@ mod tests {
         let decrypted = v.decrypt(&encrypted);
         assert_eq!(decrypted, plain);
     }
-}
\ No newline at end of file
+
+    fn alphabet(letters: &str) -> [char; 26] {
+        letters.chars().collect::<Vec<_>>().try_into().unwrap()
+    }
+
+    #[test]
+    fn vigenere_identity_alphabet_matches_standard() {
+        let standard = Vigenere::new("LEMON");
+        let keyed = Vigenere::with_alphabet("LEMON", alphabet("ABCDEFGHIJKLMNOPQRSTUVWXYZ")).unwrap();
+        let plain = "Attack at dawn!";
+        assert_eq!(keyed.encrypt(plain), standard.encrypt(plain));
+        assert_eq!(keyed.encrypt(plain), "Lxfopv ef rnhr!");
+    }
+
+    #[test]
+    fn vigenere_scrambled_alphabet_is_invertible() {
+        let standard = Vigenere::new("KEY");
+        let keyed = Vigenere::with_alphabet("KEY", alphabet("KRYPTOSABCDEFGHIJLMNQUVWXZ")).unwrap();
+        let plain = "Meet me by the old oak tree.";
+        let encrypted = keyed.encrypt(plain);
+        assert_ne!(encrypted, standard.encrypt(plain));
+        assert_eq!(keyed.decrypt(&encrypted), plain);
+        assert!(Vigenere::with_alphabet("KEY", alphabet("KKYPTOSABCDEFGHIJLMNQUVWXZ")).is_err());
+    }
+
+    #[test]
+    fn vigenere_keyword_reconstructs_letters() {
+        assert_eq!(Vigenere::new("Key!").keyword(), "KEY");
+        assert_eq!(Vigenere::new("lemon tree").keyword(), "LEMONTREE");
+        let keyed = Vigenere::with_alphabet("key", alphabet("KRYPTOSABCDEFGHIJLMNQUVWXZ")).unwrap();
+        assert_eq!(keyed.keyword(), "KEY");
+    }
+
+    #[test]
+    fn vigenere_encrypt_iter_matches_encrypt() {
+        let v = Vigenere::new("LEMON");
+        let plain = "Attack at dawn, 6am sharp!";
+        let lazy: String = v.encrypt_iter(plain).collect();
+        assert_eq!(lazy, v.encrypt(plain));
+        assert_eq!(v.encrypt_iter(plain).count(), plain.chars().count());
+    }
+}
@@ -0,0 +1,91 @@
+use super::game::{Game, GameResult, Point};
+use rand::seq::IndexedRandom;
+
+/// Picks a uniformly random legal move for the player to move.
+///
+/// Returns `None` when there is no legal move (or play has ended), in which case the bot should pass.
+///
+/// # Examples
+/// ```
+/// use puzzle_engine::go::{ai, Game};
+/// let game = Game::new(9);
+/// let point = ai::random_legal_move(&game).unwrap();
+/// assert!(game.is_legal(point));
+/// ```
+#[must_use]
+pub fn random_legal_move(game: &Game) -> Option<Point> {
+    candidates(game).choose(&mut rand::rng()).copied()
+}
+
+/// Picks the legal move that captures the most enemy stones, breaking ties in favour of the
+/// move that leaves the played group with the most liberties.
+///
+/// Returns `None` when there is no legal move (or play has ended), in which case the bot should pass.
+///
+/// # Examples
+/// ```
+/// use puzzle_engine::go::{ai, Game, Point};
+/// let mut game = Game::new(9);
+/// game.play(Point::new(0, 1)).unwrap();
+/// game.play(Point::new(0, 0)).unwrap();
+/// // Black captures the corner stone
+/// assert_eq!(ai::greedy_move(&game), Some(Point::new(1, 0)));
+/// ```
+#[must_use]
+pub fn greedy_move(game: &Game) -> Option<Point> {
+    let mut best: Option<(usize, usize, Point)> = None;
+    for point in candidates(game) {
+        let mut board = game.board.clone();
+        let Ok(captured) = board.place_stone_capturing(point, game.to_move) else {
+            continue;
+        };
+        let key = (captured.len(), board.liberties(point).len());
+        if best.is_none_or(|(captures, liberties, _)| key > (captures, liberties)) {
+            best = Some((key.0, key.1, point));
+        }
+    }
+    best.map(|(_, _, point)| point)
+}
+
+/// Legal points for the player to move, or none once play has stopped.
+fn candidates(game: &Game) -> Vec<Point> {
+    if game.result != GameResult::Ongoing || game.is_scoring() {
+        return Vec::new();
+    }
+    game.board
+        .legal_moves(game.to_move, game.ko)
+        .into_iter()
+        .filter(|&point| game.is_legal(point))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_greedy_prefers_capture() {
+        let mut game = Game::new(9);
+        // White stone at (4, 4) with a single liberty left at (4, 5)
+        for (x, y) in [(3, 4), (4, 4), (5, 4), (0, 8), (4, 3), (8, 0)] {
+            game.play(Point::new(x, y)).unwrap();
+        }
+        assert_eq!(greedy_move(&game), Some(Point::new(4, 5)));
+    }
+
+    #[test]
+    fn test_greedy_breaks_ties_by_liberties() {
+        let game = Game::new(3);
+        // Only the centre keeps four liberties
+        assert_eq!(greedy_move(&game), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_no_move_once_play_has_ended() {
+        let mut game = Game::new(9);
+        assert!(random_legal_move(&game).is_some());
+        game.resign();
+        assert_eq!(random_legal_move(&game), None);
+        assert_eq!(greedy_move(&game), None);
+    }
+}
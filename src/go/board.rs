@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 
 /// Represents a point on the Go board.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -19,6 +20,47 @@ impl Point {
     pub fn new(x: usize, y: usize) -> Self {
         Self { x, y }
     }
+
+    /// Parses an SGF coordinate pair such as `"dd"` into a [`Point`].
+    ///
+    /// The first letter is the column and the second the row, with `a` mapping to 0
+    /// through `s` mapping to 18. Returns `None` for malformed input.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::Point;
+    /// assert_eq!(Point::from_sgf("pp"), Some(Point::new(15, 15)));
+    /// assert_eq!(Point::from_sgf("zz"), None);
+    /// ```
+    #[must_use]
+    pub fn from_sgf(s: &str) -> Option<Point> {
+        let coord = |c: u8| (b'a'..=b's').contains(&c).then(|| usize::from(c - b'a'));
+        match s.as_bytes() {
+            [x, y] => Some(Point::new(coord(*x)?, coord(*y)?)),
+            _ => None,
+        }
+    }
+
+    /// Formats the point as an SGF coordinate pair, e.g. `(3, 3)` becomes `"dd"`.
+    ///
+    /// Uses the same `a`-`s` range as [`Point::from_sgf`], so only boards up to 19x19 are
+    /// covered; coordinates of 19 or more become `?`.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::Point;
+    /// assert_eq!(Point::new(3, 3).to_sgf(), "dd");
+    /// ```
+    #[must_use]
+    pub fn to_sgf(&self) -> String {
+        let letter = |v: usize| {
+            u8::try_from(v)
+                .ok()
+                .filter(|v| *v < 19)
+                .map_or('?', |v| char::from(b'a' + v))
+        };
+        format!("{}{}", letter(self.x), letter(self.y))
+    }
 }
 
 /// Enum for the two players' stones.
@@ -28,26 +70,86 @@ pub enum Stone {
     White,
 }
 
+impl Stone {
+    /// Returns the stone of the other player.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::Stone;
+    /// assert_eq!(Stone::Black.opponent(), Stone::White);
+    /// ```
+    #[must_use]
+    pub fn opponent(self) -> Stone {
+        match self {
+            Stone::Black => Stone::White,
+            Stone::White => Stone::Black,
+        }
+    }
+}
+
+/// Formats a stone as `B` or `W`, as in SGF.
+///
+/// # Examples
+/// ```
+/// use puzzle_engine::go::board::Stone;
+/// assert_eq!(format!("{}{}", Stone::Black, Stone::White), "BW");
+/// ```
+impl fmt::Display for Stone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stone::Black => write!(f, "B"),
+            Stone::White => write!(f, "W"),
+        }
+    }
+}
+
 /// Represents the Go board state.
 #[derive(Debug, Clone)]
 pub struct Board {
+    pub width: usize,
+    pub height: usize,
+    /// Side length of a square board; 0 for rectangular boards.
+    #[deprecated(note = "use `width` and `height` instead")]
     pub size: usize,
     grid: HashMap<Point, Stone>,
+    // Zobrist hash of the stones on the board, updated as stones come and go.
+    hash: u64,
 }
 
 impl Board {
-    /// Create a new empty Go board of a given size.
+    /// Create a new empty square Go board of a given size.
     ///
     /// # Examples
     /// ```
     /// use puzzle_engine::go::board::Board;
     /// let board = Board::new(9);
-    /// assert_eq!(board.size, 9);
+    /// assert_eq!((board.width, board.height), (9, 9));
     /// ```
     pub fn new(size: usize) -> Self {
+        Self::new_rect(size, size)
+    }
+
+    /// Create a new empty rectangular Go board, `width` points across and `height` down.
+    ///
+    /// The deprecated `size` field is only set for square boards and is 0 otherwise, so it never
+    /// reports a side length that does not fit the board.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Point};
+    /// let board = Board::new_rect(9, 13);
+    /// assert!(board.is_on_board(Point::new(8, 12)));
+    /// assert!(!board.is_on_board(Point::new(12, 8)));
+    /// ```
+    #[must_use]
+    #[allow(deprecated)]
+    pub fn new_rect(width: usize, height: usize) -> Self {
         Self {
-            size,
+            width,
+            height,
+            size: if width == height { width } else { 0 },
             grid: HashMap::new(),
+            hash: 0,
         }
     }
 
@@ -75,12 +177,380 @@ impl Board {
     /// board.place_stone(Point::new(1, 1), Stone::White).unwrap();
     /// ```
     pub fn place_stone(&mut self, point: Point, stone: Stone) -> Result<(), &'static str> {
+        self.place_stone_capturing(point, stone).map(|_| ())
+    }
+
+    /// Returns a copy of the board with a stone placed and any captures it makes removed,
+    /// leaving this board untouched. Useful for reading out moves without undoing them.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the point is off the board or already occupied.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Point, Stone};
+    /// let board = Board::new(9);
+    /// let next = board.with_stone(Point::new(4, 4), Stone::Black).unwrap();
+    /// assert_eq!(next.get(Point::new(4, 4)), Some(Stone::Black));
+    /// assert_eq!(board.get(Point::new(4, 4)), None);
+    /// ```
+    pub fn with_stone(&self, point: Point, stone: Stone) -> Result<Board, &'static str> {
+        let mut board = self.clone();
+        board.place_stone(point, stone)?;
+        Ok(board)
+    }
+
+    /// Places a stone and returns the points of any opposing stones it captured.
+    pub(crate) fn place_stone_capturing(&mut self, point: Point, stone: Stone) -> Result<Vec<Point>, &'static str> {
+        if !self.is_on_board(point) {
+            return Err("Point out of bounds");
+        }
         if self.grid.contains_key(&point) {
             return Err("Point already occupied");
         }
         self.grid.insert(point, stone);
-        Ok(())
+        self.hash ^= zobrist_key(point, stone);
+        Ok(self.resolve_captures(point))
+    }
+
+    /// Returns `true` if the point lies on the board.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Point};
+    /// let board = Board::new(9);
+    /// assert!(board.is_on_board(Point::new(8, 8)));
+    /// assert!(!board.is_on_board(Point::new(9, 0)));
+    /// ```
+    #[must_use]
+    pub fn is_on_board(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    /// Returns the orthogonally adjacent points that lie on the board.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Point};
+    /// let board = Board::new(9);
+    /// assert_eq!(board.neighbors(Point::new(0, 0)).len(), 2);
+    /// assert_eq!(board.neighbors(Point::new(4, 4)).len(), 4);
+    /// ```
+    #[must_use]
+    pub fn neighbors(&self, point: Point) -> Vec<Point> {
+        let mut result = Vec::with_capacity(4);
+        if point.x > 0 {
+            result.push(Point::new(point.x - 1, point.y));
+        }
+        if point.y > 0 {
+            result.push(Point::new(point.x, point.y - 1));
+        }
+        let right = Point::new(point.x + 1, point.y);
+        if self.is_on_board(right) {
+            result.push(right);
+        }
+        let down = Point::new(point.x, point.y + 1);
+        if self.is_on_board(down) {
+            result.push(down);
+        }
+        result
+    }
+
+    /// Returns the diagonally adjacent points that lie on the board, as used to tell real
+    /// eyes from false ones.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Point};
+    /// let board = Board::new(9);
+    /// assert_eq!(board.diagonal_neighbors(Point::new(0, 0)), vec![Point::new(1, 1)]);
+    /// assert_eq!(board.diagonal_neighbors(Point::new(4, 0)).len(), 2);
+    /// ```
+    #[must_use]
+    pub fn diagonal_neighbors(&self, point: Point) -> Vec<Point> {
+        let mut result = Vec::with_capacity(4);
+        for (dx, dy) in [(-1, -1), (1, -1), (-1, 1), (1, 1)] {
+            let (Some(x), Some(y)) = (point.x.checked_add_signed(dx), point.y.checked_add_signed(dy)) else {
+                continue;
+            };
+            let diagonal = Point::new(x, y);
+            if self.is_on_board(diagonal) {
+                result.push(diagonal);
+            }
+        }
+        result
+    }
+
+    /// Returns every stone connected to the stone at `point`, including itself.
+    ///
+    /// Returns an empty set if the point is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Point, Stone};
+    /// let mut board = Board::new(9);
+    /// board.place_stone(Point::new(2, 2), Stone::Black).unwrap();
+    /// board.place_stone(Point::new(2, 3), Stone::Black).unwrap();
+    /// assert_eq!(board.group(Point::new(2, 2)).len(), 2);
+    /// ```
+    #[must_use]
+    pub fn group(&self, point: Point) -> HashSet<Point> {
+        let mut group = HashSet::new();
+        let Some(color) = self.get(point) else {
+            return group;
+        };
+        let mut stack = vec![point];
+        group.insert(point);
+        while let Some(current) = stack.pop() {
+            for neighbor in self.neighbors(current) {
+                if self.get(neighbor) == Some(color) && group.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+        group
+    }
+
+    /// Returns the empty points adjacent to the group containing `point`.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Point, Stone};
+    /// let mut board = Board::new(9);
+    /// board.place_stone(Point::new(0, 0), Stone::Black).unwrap();
+    /// assert_eq!(board.liberties(Point::new(0, 0)).len(), 2);
+    /// ```
+    #[must_use]
+    pub fn liberties(&self, point: Point) -> HashSet<Point> {
+        self.group(point)
+            .into_iter()
+            .flat_map(|stone| self.neighbors(stone))
+            .filter(|neighbor| !self.grid.contains_key(neighbor))
+            .collect()
+    }
+
+    /// Returns how many stones of the given color are on the board.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Point, Stone};
+    /// let mut board = Board::new(9);
+    /// board.place_stone(Point::new(1, 1), Stone::Black).unwrap();
+    /// assert_eq!(board.count(Stone::Black), 1);
+    /// assert_eq!(board.count(Stone::White), 0);
+    /// ```
+    #[must_use]
+    pub fn count(&self, stone: Stone) -> usize {
+        self.grid.values().filter(|s| **s == stone).count()
+    }
+
+    /// Iterates over every stone on the board along with its point.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Point, Stone};
+    /// let mut board = Board::new(9);
+    /// board.place_stone(Point::new(1, 1), Stone::White).unwrap();
+    /// let stones: Vec<_> = board.stones().collect();
+    /// assert_eq!(stones, vec![(Point::new(1, 1), Stone::White)]);
+    /// ```
+    pub fn stones(&self) -> impl Iterator<Item = (Point, Stone)> + '_ {
+        self.grid.iter().map(|(point, stone)| (*point, *stone))
+    }
+
+    /// Returns `true` if the empty `point` is a true eye for `stone`.
+    ///
+    /// Every orthogonal neighbor must be a friendly stone. The diagonals decide whether the eye
+    /// is real: an interior point tolerates at most one enemy diagonal, while a point on the
+    /// edge or in the corner tolerates none. Otherwise the eye is false and can be destroyed.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Point, Stone};
+    /// let mut board = Board::new(9);
+    /// for (x, y) in [(3, 4), (5, 4), (4, 3), (4, 5)] {
+    ///     board.place_stone(Point::new(x, y), Stone::Black).unwrap();
+    /// }
+    /// assert!(board.is_eye(Point::new(4, 4), Stone::Black));
+    /// assert!(!board.is_eye(Point::new(4, 4), Stone::White));
+    /// ```
+    #[must_use]
+    pub fn is_eye(&self, point: Point, stone: Stone) -> bool {
+        if !self.is_on_board(point) || self.grid.contains_key(&point) {
+            return false;
+        }
+        if !self.neighbors(point).into_iter().all(|n| self.get(n) == Some(stone)) {
+            return false;
+        }
+        let diagonals = self.diagonal_neighbors(point);
+        let enemy = diagonals
+            .iter()
+            .filter(|d| matches!(self.get(**d), Some(other) if other != stone))
+            .count();
+        let allowed = usize::from(diagonals.len() == 4);
+        enemy <= allowed
+    }
+
+    /// Counts empty points by owner, returning `(black territory, white territory, dame)`.
+    ///
+    /// Each connected empty region is flood-filled and classified by the colors of the stones
+    /// bordering it. A region touched by only one color is that color's territory; a region
+    /// bordered by both colors, or by none at all, is neutral (dame).
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Point, Stone};
+    /// let mut board = Board::new(3);
+    /// for y in 0..3 {
+    ///     board.place_stone(Point::new(1, y), Stone::Black).unwrap();
+    /// }
+    /// assert_eq!(board.count_territory(), (6, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn count_territory(&self) -> (usize, usize, usize) {
+        let (mut black, mut white, mut dame) = (0, 0, 0);
+        let mut seen = HashSet::new();
+        for x in 0..self.width {
+            for y in 0..self.height {
+                let start = Point::new(x, y);
+                if self.grid.contains_key(&start) || !seen.insert(start) {
+                    continue;
+                }
+                let mut region = 0;
+                let (mut touches_black, mut touches_white) = (false, false);
+                let mut stack = vec![start];
+                while let Some(current) = stack.pop() {
+                    region += 1;
+                    for neighbor in self.neighbors(current) {
+                        match self.get(neighbor) {
+                            Some(Stone::Black) => touches_black = true,
+                            Some(Stone::White) => touches_white = true,
+                            None => {
+                                if seen.insert(neighbor) {
+                                    stack.push(neighbor);
+                                }
+                            }
+                        }
+                    }
+                }
+                match (touches_black, touches_white) {
+                    (true, false) => black += region,
+                    (false, true) => white += region,
+                    _ => dame += region,
+                }
+            }
+        }
+        (black, white, dame)
+    }
+
+    /// Returns every point where `to_move` may play: empty, not suicide, and not the ko point.
+    ///
+    /// Points are listed row by row.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Stone};
+    /// let board = Board::new(3);
+    /// assert_eq!(board.legal_moves(Stone::Black, None).len(), 9);
+    /// ```
+    #[must_use]
+    pub fn legal_moves(&self, to_move: Stone, ko: Option<Point>) -> Vec<Point> {
+        (0..self.height)
+            .flat_map(|y| (0..self.width).map(move |x| Point::new(x, y)))
+            .filter(|point| !self.grid.contains_key(point))
+            .filter(|point| ko != Some(*point) && !self.is_suicide(*point, to_move))
+            .collect()
+    }
+
+    /// Returns `true` if playing `stone` on the empty `point` would leave its own group
+    /// without liberties and capture nothing.
+    ///
+    /// Off-board and occupied points are never reported as suicide.
+    pub(crate) fn is_suicide(&self, point: Point, stone: Stone) -> bool {
+        if !self.is_on_board(point) || self.grid.contains_key(&point) {
+            return false;
+        }
+        self.neighbors(point).into_iter().all(|neighbor| match self.get(neighbor) {
+            None => false,
+            // Joining a friendly group that has another liberty keeps the stone alive
+            Some(other) if other == stone => self.liberties(neighbor).len() == 1,
+            // Taking an enemy group's last liberty captures it
+            Some(_) => self.liberties(neighbor).len() > 1,
+        })
+    }
+
+    /// Returns a Zobrist hash of the stones on the board.
+    ///
+    /// Boards holding the same stones hash equally, however they were reached. The hash is
+    /// kept up to date as stones are placed and captured, so this is a constant-time lookup.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::board::{Board, Point, Stone};
+    /// let mut a = Board::new(9);
+    /// let mut b = Board::new(9);
+    /// a.place_stone(Point::new(1, 1), Stone::Black).unwrap();
+    /// a.place_stone(Point::new(2, 2), Stone::White).unwrap();
+    /// b.place_stone(Point::new(2, 2), Stone::White).unwrap();
+    /// b.place_stone(Point::new(1, 1), Stone::Black).unwrap();
+    /// assert_eq!(a.hash(), b.hash());
+    /// ```
+    #[must_use]
+    pub fn hash(&self) -> u64 {
+        self.hash
     }
+
+    /// Removes a single stone, keeping the hash in sync.
+    fn remove(&mut self, point: Point) {
+        if let Some(stone) = self.grid.remove(&point) {
+            self.hash ^= zobrist_key(point, stone);
+        }
+    }
+
+    /// Removes the whole group containing `point`, returning the removed points.
+    pub(crate) fn remove_group(&mut self, point: Point) -> Vec<Point> {
+        let group: Vec<Point> = self.group(point).into_iter().collect();
+        for stone in &group {
+            self.remove(*stone);
+        }
+        group
+    }
+
+    /// Removes any opposing groups adjacent to `point` that have no liberties left.
+    ///
+    /// Returns the points of all removed stones.
+    fn resolve_captures(&mut self, point: Point) -> Vec<Point> {
+        let Some(color) = self.get(point) else {
+            return Vec::new();
+        };
+        let mut captured = Vec::new();
+        for neighbor in self.neighbors(point) {
+            match self.get(neighbor) {
+                Some(other) if other != color && self.liberties(neighbor).is_empty() => {
+                    for stone in self.group(neighbor) {
+                        self.remove(stone);
+                        captured.push(stone);
+                    }
+                }
+                _ => {}
+            }
+        }
+        captured
+    }
+}
+
+/// Pseudo-random Zobrist key for a stone on a point, derived with `SplitMix64` so that no
+/// table is needed for arbitrary board sizes.
+fn zobrist_key(point: Point, stone: Stone) -> u64 {
+    let color = match stone {
+        Stone::Black => 0,
+        Stone::White => 1,
+    };
+    let mut z = ((point.x as u64) << 33 | (point.y as u64) << 1 | color).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
 }
 
 #[cfg(test)]
@@ -90,7 +560,62 @@ mod tests {
     #[test]
     fn test_board_creation() {
         let board = Board::new(19);
-        assert_eq!(board.size, 19);
+        assert_eq!((board.width, board.height), (19, 19));
+    }
+
+    #[test]
+    fn test_stone_opponent_and_display() {
+        assert_eq!(Stone::Black.opponent(), Stone::White);
+        assert_eq!(Stone::White.opponent(), Stone::Black);
+        assert_eq!(Stone::Black.to_string(), "B");
+        assert_eq!(Stone::White.to_string(), "W");
+    }
+
+    #[test]
+    fn test_with_stone_leaves_original_unchanged() {
+        let mut board = Board::new(9);
+        board.place_stone(Point::new(0, 0), Stone::White).unwrap();
+        board.place_stone(Point::new(1, 0), Stone::Black).unwrap();
+        let hash = board.hash();
+
+        let next = board.with_stone(Point::new(0, 1), Stone::Black).unwrap();
+        assert_eq!(next.get(Point::new(0, 1)), Some(Stone::Black));
+        assert_eq!(next.get(Point::new(0, 0)), None, "The corner stone is captured.");
+
+        assert_eq!(board.get(Point::new(0, 0)), Some(Stone::White));
+        assert_eq!(board.get(Point::new(0, 1)), None);
+        assert_eq!(board.hash(), hash);
+        assert!(board.with_stone(Point::new(1, 0), Stone::White).is_err());
+    }
+
+    #[test]
+    fn test_diagonal_neighbors() {
+        let board = Board::new(9);
+        let center = board.diagonal_neighbors(Point::new(4, 4));
+        assert_eq!(center.len(), 4);
+        for point in [Point::new(3, 3), Point::new(5, 3), Point::new(3, 5), Point::new(5, 5)] {
+            assert!(center.contains(&point));
+        }
+        assert_eq!(board.diagonal_neighbors(Point::new(8, 8)), vec![Point::new(7, 7)]);
+        assert_eq!(board.diagonal_neighbors(Point::new(0, 4)).len(), 2);
+    }
+
+    #[test]
+    fn test_rectangular_board_bounds() {
+        let mut board = Board::new_rect(9, 13);
+        assert!(board.place_stone(Point::new(8, 12), Stone::Black).is_ok());
+        assert_eq!(board.place_stone(Point::new(9, 0), Stone::White), Err("Point out of bounds"));
+        assert_eq!(board.neighbors(Point::new(8, 12)).len(), 2);
+        assert_eq!(board.neighbors(Point::new(8, 11)).len(), 3);
+        assert_eq!(board.legal_moves(Stone::White, None).len(), 9 * 13 - 1);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_size_alias_only_for_square_boards() {
+        assert_eq!(Board::new(19).size, 19);
+        assert_eq!(Board::new_rect(7, 7).size, 7);
+        assert_eq!(Board::new_rect(9, 13).size, 0);
     }
 
     #[test]
@@ -109,4 +634,165 @@ mod tests {
         let result = board.place_stone(p, Stone::Black);
         assert_eq!(result, Err("Point already occupied"));
     }
+
+    #[test]
+    fn test_point_sgf_round_trip() {
+        assert_eq!(Point::new(3, 3).to_sgf(), "dd");
+        assert_eq!(Point::from_sgf("pp"), Some(Point::new(15, 15)));
+        assert_eq!(Point::from_sgf("as"), Some(Point::new(0, 18)));
+        for p in [Point::new(0, 0), Point::new(18, 18), Point::new(4, 11)] {
+            assert_eq!(Point::from_sgf(&p.to_sgf()), Some(p));
+        }
+        assert_eq!(Point::new(19, 25).to_sgf(), "??");
+        assert_eq!(Point::from_sgf(&Point::new(2, 19).to_sgf()), None);
+    }
+
+    #[test]
+    fn test_point_from_sgf_rejects_malformed() {
+        assert_eq!(Point::from_sgf(""), None);
+        assert_eq!(Point::from_sgf("a"), None);
+        assert_eq!(Point::from_sgf("abc"), None);
+        assert_eq!(Point::from_sgf("tt"), None);
+        assert_eq!(Point::from_sgf("AA"), None);
+    }
+
+    #[test]
+    fn test_place_stone_out_of_bounds_should_error() {
+        let mut board = Board::new(9);
+        assert_eq!(board.place_stone(Point::new(9, 0), Stone::Black), Err("Point out of bounds"));
+    }
+
+    #[test]
+    fn test_count_after_placements_and_capture() {
+        let mut board = Board::new(9);
+        board.place_stone(Point::new(1, 1), Stone::White).unwrap();
+        board.place_stone(Point::new(5, 5), Stone::White).unwrap();
+        board.place_stone(Point::new(1, 0), Stone::Black).unwrap();
+        board.place_stone(Point::new(0, 1), Stone::Black).unwrap();
+        board.place_stone(Point::new(2, 1), Stone::Black).unwrap();
+        assert_eq!(board.count(Stone::Black), 3);
+        assert_eq!(board.count(Stone::White), 2);
+
+        // Fill the last liberty of the white stone at (1, 1)
+        board.place_stone(Point::new(1, 2), Stone::Black).unwrap();
+        assert_eq!(board.count(Stone::Black), 4);
+        assert_eq!(board.count(Stone::White), 1);
+        assert_eq!(board.get(Point::new(1, 1)), None);
+    }
+
+    #[test]
+    fn test_stones_lists_every_stone() {
+        let mut board = Board::new(9);
+        board.place_stone(Point::new(0, 0), Stone::Black).unwrap();
+        board.place_stone(Point::new(3, 4), Stone::White).unwrap();
+        let mut stones: Vec<_> = board.stones().collect();
+        stones.sort_by_key(|(p, _)| (p.x, p.y));
+        assert_eq!(stones, vec![(Point::new(0, 0), Stone::Black), (Point::new(3, 4), Stone::White)]);
+    }
+
+    #[test]
+    fn test_true_eye_and_false_eye() {
+        let mut board = Board::new(9);
+        for (x, y) in [(3, 4), (5, 4), (4, 3), (4, 5)] {
+            board.place_stone(Point::new(x, y), Stone::Black).unwrap();
+        }
+        board.place_stone(Point::new(3, 3), Stone::White).unwrap();
+        assert!(board.is_eye(Point::new(4, 4), Stone::Black));
+
+        // A second enemy diagonal makes the eye false
+        board.place_stone(Point::new(5, 5), Stone::White).unwrap();
+        assert!(!board.is_eye(Point::new(4, 4), Stone::Black));
+    }
+
+    #[test]
+    fn test_edge_eye_tolerates_no_enemy_diagonal() {
+        let mut board = Board::new(9);
+        for (x, y) in [(3, 0), (5, 0), (4, 1)] {
+            board.place_stone(Point::new(x, y), Stone::Black).unwrap();
+        }
+        assert!(board.is_eye(Point::new(4, 0), Stone::Black));
+        board.place_stone(Point::new(3, 1), Stone::White).unwrap();
+        assert!(!board.is_eye(Point::new(4, 0), Stone::Black));
+    }
+
+    #[test]
+    fn test_count_territory_separates_dame() {
+        let mut board = Board::new(5);
+        for y in 0..5 {
+            board.place_stone(Point::new(1, y), Stone::Black).unwrap();
+            board.place_stone(Point::new(3, y), Stone::White).unwrap();
+        }
+        // Column 0 is Black's, column 4 is White's, column 2 touches both
+        assert_eq!(board.count_territory(), (5, 5, 5));
+    }
+
+    #[test]
+    fn test_count_territory_empty_board_is_dame() {
+        let board = Board::new(9);
+        assert_eq!(board.count_territory(), (0, 0, 81));
+    }
+
+    #[test]
+    fn test_legal_moves_on_empty_board() {
+        let board = Board::new(3);
+        assert_eq!(board.legal_moves(Stone::Black, None).len(), 9);
+        assert_eq!(board.legal_moves(Stone::White, Some(Point::new(1, 1))).len(), 8);
+    }
+
+    #[test]
+    fn test_legal_moves_exclude_suicide_unless_capturing() {
+        let mut board = Board::new(3);
+        board.place_stone(Point::new(1, 0), Stone::Black).unwrap();
+        board.place_stone(Point::new(0, 1), Stone::Black).unwrap();
+        assert!(!board.legal_moves(Stone::White, None).contains(&Point::new(0, 0)));
+        assert!(board.legal_moves(Stone::Black, None).contains(&Point::new(0, 0)));
+
+        // Once the black stone at (1, 0) is in atari, White may fill the corner to capture it
+        board.place_stone(Point::new(2, 0), Stone::White).unwrap();
+        board.place_stone(Point::new(1, 1), Stone::White).unwrap();
+        assert!(board.legal_moves(Stone::White, None).contains(&Point::new(0, 0)));
+    }
+
+    #[test]
+    fn test_hash_restored_after_removal() {
+        let mut board = Board::new(9);
+        board.place_stone(Point::new(4, 4), Stone::Black).unwrap();
+        let before = board.hash();
+        board.place_stone(Point::new(2, 2), Stone::White).unwrap();
+        assert_ne!(board.hash(), before);
+        board.remove_group(Point::new(2, 2));
+        assert_eq!(board.hash(), before);
+        board.remove_group(Point::new(4, 4));
+        assert_eq!(board.hash(), Board::new(9).hash());
+    }
+
+    #[test]
+    fn test_hash_tracks_captures_and_colors() {
+        let mut captured = Board::new(9);
+        captured.place_stone(Point::new(0, 0), Stone::White).unwrap();
+        captured.place_stone(Point::new(1, 0), Stone::Black).unwrap();
+        captured.place_stone(Point::new(0, 1), Stone::Black).unwrap();
+
+        let mut direct = Board::new(9);
+        direct.place_stone(Point::new(0, 1), Stone::Black).unwrap();
+        direct.place_stone(Point::new(1, 0), Stone::Black).unwrap();
+        assert_eq!(captured.hash(), direct.hash());
+
+        let mut white = Board::new(9);
+        white.place_stone(Point::new(0, 1), Stone::White).unwrap();
+        white.place_stone(Point::new(1, 0), Stone::White).unwrap();
+        assert_ne!(white.hash(), direct.hash());
+    }
+
+    #[test]
+    fn test_capture_multi_stone_group() {
+        let mut board = Board::new(9);
+        board.place_stone(Point::new(0, 0), Stone::White).unwrap();
+        board.place_stone(Point::new(1, 0), Stone::White).unwrap();
+        board.place_stone(Point::new(0, 1), Stone::Black).unwrap();
+        board.place_stone(Point::new(1, 1), Stone::Black).unwrap();
+        assert_eq!(board.liberties(Point::new(0, 0)).len(), 1);
+        board.place_stone(Point::new(2, 0), Stone::Black).unwrap();
+        assert_eq!(board.count(Stone::White), 0);
+    }
 } 
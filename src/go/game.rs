@@ -1,11 +1,14 @@
 pub use super::board::{Board, Stone, Point};
+use std::collections::HashSet;
+use std::fmt::Write;
 
 /// Represents the result of a Go game.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum GameResult {
     Ongoing,
     Resigned(Stone),
-    Finished { black_score: usize, white_score: usize },
+    /// Area scores; White's includes komi.
+    Finished { black_score: f32, white_score: f32 },
 }
 
 /// Represents the game state and logic for a game of Go.
@@ -14,46 +17,374 @@ pub struct Game {
     pub board: Board,
     pub to_move: Stone,
     pub result: GameResult,
+    /// Number of white stones captured by Black.
+    pub black_captures: usize,
+    /// Number of black stones captured by White.
+    pub white_captures: usize,
+    /// The point the player to move may not play on due to the ko rule.
+    pub ko: Option<Point>,
+    /// Number of passes in a row; two consecutive passes start the scoring phase.
+    pub consecutive_passes: usize,
+    /// Stones marked dead during the scoring phase.
+    pub dead: HashSet<Point>,
+    /// Points added to White's score as compensation for moving second.
+    pub komi: f32,
+    history: Vec<Snapshot>,
+    // Hashes of every board position reached, for the positional superko rule.
+    seen_positions: HashSet<u64>,
+}
+
+/// The state needed to take back a move.
+#[derive(Debug, Clone)]
+struct Snapshot {
+    board: Board,
+    to_move: Stone,
+    black_captures: usize,
+    white_captures: usize,
+    ko: Option<Point>,
+    consecutive_passes: usize,
 }
 
 impl Game {
-    /// Creates a new Go game with the specified board size.
+    /// Creates a new Go game with the specified board size and 6.5 komi.
     ///
     /// # Examples
     /// ```
     /// use puzzle_engine::go::game::Game;
     /// let game = Game::new(19);
-    /// assert_eq!(game.board.size, 19);
+    /// assert_eq!(game.board.width, 19);
     /// ```
     pub fn new(size: usize) -> Self {
+        Self::new_with_komi(size, 6.5)
+    }
+
+    /// Creates a new Go game with the given komi instead of the default 6.5.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::game::Game;
+    /// let game = Game::new_with_komi(9, 5.5);
+    /// assert_eq!(game.komi, 5.5);
+    /// ```
+    #[must_use]
+    pub fn new_with_komi(size: usize, komi: f32) -> Self {
         Self {
             board: Board::new(size),
             to_move: Stone::Black,
             result: GameResult::Ongoing,
+            black_captures: 0,
+            white_captures: 0,
+            ko: None,
+            consecutive_passes: 0,
+            dead: HashSet::new(),
+            komi,
+            history: Vec::new(),
+            seen_positions: HashSet::from([0]),
         }
     }
 
-    /// Attempts to play a move. Returns an error if the move is illegal.
+    /// Attempts to play a move, returning the points of any stones it captured.
+    /// Returns an error if the move is illegal.
     ///
     /// # Examples
     /// ```
     /// use puzzle_engine::go::game::{Game,Point, Stone};
     /// let mut game = Game::new(9);
-    /// game.play(Point::new(3, 3)).unwrap();
+    /// assert!(game.play(Point::new(3, 3)).unwrap().is_empty());
     /// ```
-    pub fn play(&mut self, point: Point) -> Result<(), &'static str> {
+    pub fn play(&mut self, point: Point) -> Result<Vec<Point>, &'static str> {
         if self.result != GameResult::Ongoing {
             return Err("Game is already over");
         }
+        if self.is_scoring() {
+            return Err("Game is in the scoring phase");
+        }
 
-        self.board.place_stone(point, self.to_move)?;
-        self.to_move = match self.to_move {
-            Stone::Black => Stone::White,
-            Stone::White => Stone::Black,
+        if self.ko == Some(point) {
+            return Err("Move violates the ko rule");
+        }
+        if self.board.is_suicide(point, self.to_move) {
+            return Err("Suicide is not allowed");
+        }
+
+        let snapshot = self.snapshot();
+        let captured = self.board.place_stone_capturing(point, self.to_move)?;
+        if !self.seen_positions.insert(self.board.hash()) {
+            self.board = snapshot.board;
+            return Err("Move violates the superko rule");
+        }
+        self.history.push(snapshot);
+        self.consecutive_passes = 0;
+
+        match self.to_move {
+            Stone::Black => self.black_captures += captured.len(),
+            Stone::White => self.white_captures += captured.len(),
+        }
+        // A lone stone that captured a single stone and sits in its only liberty creates a ko.
+        self.ko = match captured.as_slice() {
+            [single] if self.board.group(point).len() == 1 && self.board.liberties(point).len() == 1 => Some(*single),
+            _ => None,
         };
+        self.to_move = self.to_move.opponent();
+        Ok(captured)
+    }
+
+    /// Returns whether [`Game::play`] would accept a stone at `point`, without playing it.
+    ///
+    /// The point must be on the board and empty, and the move must be neither suicide nor
+    /// an immediate ko recapture, and must not repeat an earlier board position (superko),
+    /// while the game is still in play.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::game::{Game, Point};
+    /// let mut game = Game::new(9);
+    /// game.play(Point::new(3, 3)).unwrap();
+    /// assert!(!game.is_legal(Point::new(3, 3)));
+    /// assert!(game.is_legal(Point::new(4, 4)));
+    /// ```
+    #[must_use]
+    pub fn is_legal(&self, point: Point) -> bool {
+        self.result == GameResult::Ongoing
+            && !self.is_scoring()
+            && self.board.is_on_board(point)
+            && self.board.get(point).is_none()
+            && self.ko != Some(point)
+            && !self.board.is_suicide(point, self.to_move)
+            && !self.repeats_position(point)
+    }
+
+    /// Whether playing at `point` would recreate a board position seen earlier in the game.
+    fn repeats_position(&self, point: Point) -> bool {
+        let mut board = self.board.clone();
+        board.place_stone_capturing(point, self.to_move).is_ok()
+            && self.seen_positions.contains(&board.hash())
+    }
+
+    /// Passes the turn. Two passes in a row end play and start the scoring phase.
+    ///
+    /// # Errors
+    /// Returns an error if the game is over or already in the scoring phase.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::game::Game;
+    /// let mut game = Game::new(9);
+    /// game.pass().unwrap();
+    /// game.pass().unwrap();
+    /// assert!(game.is_scoring());
+    /// ```
+    pub fn pass(&mut self) -> Result<(), &'static str> {
+        if self.result != GameResult::Ongoing {
+            return Err("Game is already over");
+        }
+        if self.is_scoring() {
+            return Err("Game is in the scoring phase");
+        }
+        self.history.push(self.snapshot());
+        self.consecutive_passes += 1;
+        self.ko = None;
+        self.to_move = self.to_move.opponent();
+        Ok(())
+    }
+
+    /// Returns `true` once both players have passed and dead stones are being marked.
+    #[must_use]
+    pub fn is_scoring(&self) -> bool {
+        self.result == GameResult::Ongoing && self.consecutive_passes >= 2
+    }
+
+    /// Marks the group containing `point` as dead.
+    ///
+    /// # Errors
+    /// Returns an error outside the scoring phase or if the point is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::game::{Game, Point};
+    /// let mut game = Game::new(9);
+    /// game.play(Point::new(4, 4)).unwrap();
+    /// game.pass().unwrap();
+    /// game.pass().unwrap();
+    /// game.mark_dead(Point::new(4, 4)).unwrap();
+    /// assert!(game.dead.contains(&Point::new(4, 4)));
+    /// ```
+    pub fn mark_dead(&mut self, point: Point) -> Result<(), &'static str> {
+        if !self.is_scoring() {
+            return Err("Dead stones can only be marked in the scoring phase");
+        }
+        let group = self.board.group(point);
+        if group.is_empty() {
+            return Err("No stone at point");
+        }
+        self.dead.extend(group);
         Ok(())
     }
 
+    /// Computes area scores `(black, white)`: stones on the board plus surrounded territory,
+    /// with the game's komi added to White. Stones marked dead are treated as already removed.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::game::{Game, Point};
+    /// let mut game = Game::new(3);
+    /// game.play(Point::new(1, 1)).unwrap();
+    /// assert_eq!(game.score(), (9.0, 6.5));
+    /// ```
+    #[must_use]
+    pub fn score(&self) -> (f32, f32) {
+        self.score_with_komi(self.komi)
+    }
+
+    /// Computes area scores like [`Game::score`], but with the given komi instead of the game's.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::game::{Game, Point};
+    /// let mut game = Game::new(3);
+    /// game.play(Point::new(1, 1)).unwrap();
+    /// assert_eq!(game.score_with_komi(0.5), (9.0, 0.5));
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn score_with_komi(&self, komi: f32) -> (f32, f32) {
+        let mut board = self.board.clone();
+        for point in &self.dead {
+            if board.get(*point).is_some() {
+                board.remove_group(*point);
+            }
+        }
+        let (black_territory, white_territory, _) = board.count_territory();
+        let black = board.count(Stone::Black) + black_territory;
+        let white = board.count(Stone::White) + white_territory;
+        (black as f32, white as f32 + komi)
+    }
+
+    /// Removes the dead stones, scores the final position and ends the game.
+    ///
+    /// Passing `Some(komi)` overrides the game's komi; `None` uses it. Dead stones are added
+    /// to the opponent's capture count.
+    ///
+    /// # Errors
+    /// Returns an error outside the scoring phase.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::game::{Game, GameResult, Point};
+    /// let mut game = Game::new(3);
+    /// game.play(Point::new(1, 1)).unwrap();
+    /// game.pass().unwrap();
+    /// game.pass().unwrap();
+    /// game.finalize_score(Some(0.5)).unwrap();
+    /// assert_eq!(game.result, GameResult::Finished { black_score: 9.0, white_score: 0.5 });
+    /// ```
+    pub fn finalize_score(&mut self, komi: Option<f32>) -> Result<(), &'static str> {
+        if !self.is_scoring() {
+            return Err("The game can only be scored in the scoring phase");
+        }
+        let (black_score, white_score) = self.score_with_komi(komi.unwrap_or(self.komi));
+        for point in std::mem::take(&mut self.dead) {
+            let Some(stone) = self.board.get(point) else {
+                continue;
+            };
+            let removed = self.board.remove_group(point).len();
+            match stone {
+                Stone::Black => self.white_captures += removed,
+                Stone::White => self.black_captures += removed,
+            }
+        }
+        self.result = GameResult::Finished { black_score, white_score };
+        Ok(())
+    }
+
+    /// Captures the state needed to take back the next move.
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            board: self.board.clone(),
+            to_move: self.to_move,
+            black_captures: self.black_captures,
+            white_captures: self.white_captures,
+            ko: self.ko,
+            consecutive_passes: self.consecutive_passes,
+        }
+    }
+
+    /// Takes back the last move, restoring the board, turn, capture counts and ko point.
+    ///
+    /// # Errors
+    /// Returns an error if no moves have been played, or once the game has ended by
+    /// resignation or [`Game::finalize_score`].
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::game::{Game, Point, Stone};
+    /// let mut game = Game::new(9);
+    /// game.play(Point::new(3, 3)).unwrap();
+    /// game.undo().unwrap();
+    /// assert_eq!(game.board.get(Point::new(3, 3)), None);
+    /// assert_eq!(game.to_move, Stone::Black);
+    /// ```
+    pub fn undo(&mut self) -> Result<(), &'static str> {
+        if self.result != GameResult::Ongoing {
+            return Err("Game is already over");
+        }
+        let snapshot = self.history.pop().ok_or("No moves to undo")?;
+        // A pass leaves the board unchanged, so only a played stone added a new position
+        if snapshot.board.hash() != self.board.hash() {
+            self.seen_positions.remove(&self.board.hash());
+        }
+        self.board = snapshot.board;
+        self.to_move = snapshot.to_move;
+        self.black_captures = snapshot.black_captures;
+        self.white_captures = snapshot.white_captures;
+        self.ko = snapshot.ko;
+        self.consecutive_passes = snapshot.consecutive_passes;
+        self.dead.clear();
+        Ok(())
+    }
+
+    /// Renders the board as text, one row per line with row `y = 0` first.
+    ///
+    /// Stones are drawn with their [`Display`](std::fmt::Display) form (`B` or `W`) and empty
+    /// points as `.`. The stone at `last_move`, if any, is wrapped in parentheses. A footer names
+    /// the side to move and the capture counts.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::go::game::{Game, Point};
+    /// let mut game = Game::new(3);
+    /// game.play(Point::new(1, 1)).unwrap();
+    /// let text = game.render(Some(Point::new(1, 1)));
+    /// assert!(text.starts_with(" .  .  . \n . (B) . \n"));
+    /// assert!(text.ends_with("W to move. Captures: B 0, W 0\n"));
+    /// ```
+    #[must_use]
+    pub fn render(&self, last_move: Option<Point>) -> String {
+        let mut out = String::new();
+        for y in 0..self.board.height {
+            for x in 0..self.board.width {
+                let point = Point::new(x, y);
+                let glyph = self.board.get(point).map_or_else(|| ".".to_string(), |stone| stone.to_string());
+                let (open, close) = if last_move == Some(point) { ('(', ')') } else { (' ', ' ') };
+                out.push(open);
+                out.push_str(&glyph);
+                out.push(close);
+            }
+            out.push('\n');
+        }
+        writeln!(
+            out,
+            "{} to move. Captures: {} {}, {} {}",
+            self.to_move,
+            Stone::Black,
+            self.black_captures,
+            Stone::White,
+            self.white_captures
+        )
+        .expect("writing to a String cannot fail");
+        out
+    }
+
     /// Forfeits the game for the current player.
     ///
     /// # Examples
@@ -75,7 +406,7 @@ mod tests {
     #[test]
     fn test_game_initial_state() {
         let game = Game::new(13);
-        assert_eq!(game.board.size, 13);
+        assert_eq!(game.board.width, 13);
         assert_eq!(game.to_move, Stone::Black);
         assert_eq!(game.result, GameResult::Ongoing);
     }
@@ -88,6 +419,20 @@ mod tests {
         assert_eq!(game.to_move, Stone::White);
     }
 
+    #[test]
+    fn test_render_marks_last_move_and_names_side_to_move() {
+        let mut game = Game::new(5);
+        game.play(Point::new(2, 1)).unwrap();
+        game.play(Point::new(3, 3)).unwrap();
+        let text = game.render(Some(Point::new(3, 3)));
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[1], " .  .  B  .  . ");
+        assert_eq!(lines[3], " .  .  . (W) . ");
+        assert_eq!(lines[5], "B to move. Captures: B 0, W 0");
+        assert!(!game.render(None).contains('('));
+    }
+
     #[test]
     fn test_resign_ends_game() {
         let mut game = Game::new(9);
@@ -102,4 +447,228 @@ mod tests {
         let result = game.play(Point::new(2, 2));
         assert_eq!(result, Err("Game is already over"));
     }
+
+    #[test]
+    fn test_cannot_finalize_score_outside_scoring_phase() {
+        let mut game = Game::new(9);
+        assert_eq!(game.finalize_score(None), Err("The game can only be scored in the scoring phase"));
+        assert_eq!(game.result, GameResult::Ongoing);
+
+        game.pass().unwrap();
+        game.pass().unwrap();
+        game.resign();
+        assert_eq!(game.finalize_score(None), Err("The game can only be scored in the scoring phase"));
+        assert_eq!(game.result, GameResult::Resigned(Stone::Black));
+    }
+
+    #[test]
+    fn test_play_counts_captures() {
+        let mut game = Game::new(9);
+        for (x, y) in [(1, 0), (0, 0), (0, 1)] {
+            game.play(Point::new(x, y)).unwrap();
+        }
+        assert_eq!(game.board.get(Point::new(0, 0)), None);
+        assert_eq!(game.black_captures, 1);
+        assert_eq!(game.white_captures, 0);
+    }
+
+    #[test]
+    fn test_play_returns_captured_points() {
+        let mut game = Game::new(9);
+        assert_eq!(game.play(Point::new(1, 0)), Ok(vec![]));
+        assert_eq!(game.play(Point::new(0, 0)), Ok(vec![]));
+        assert_eq!(game.play(Point::new(0, 1)), Ok(vec![Point::new(0, 0)]));
+    }
+
+    #[test]
+    fn test_ko_recapture_is_rejected() {
+        let mut game = Game::new(9);
+        // Black: (1,0) (0,1) (1,2) (5,5) ; White: (2,0) (3,1) (2,2) then White plays (1,1)
+        let moves = [(1, 0), (2, 0), (0, 1), (3, 1), (1, 2), (2, 2), (5, 5), (1, 1)];
+        for (x, y) in moves {
+            game.play(Point::new(x, y)).unwrap();
+        }
+        // Black captures the white stone at (1, 1) by playing (2, 1)
+        game.play(Point::new(2, 1)).unwrap();
+        assert_eq!(game.board.get(Point::new(1, 1)), None);
+        assert_eq!(game.ko, Some(Point::new(1, 1)));
+        assert_eq!(game.play(Point::new(1, 1)), Err("Move violates the ko rule"));
+    }
+
+    #[test]
+    fn test_undo_restores_captured_stones() {
+        let mut game = Game::new(9);
+        for (x, y) in [(1, 0), (0, 0), (0, 1)] {
+            game.play(Point::new(x, y)).unwrap();
+        }
+        assert_eq!(game.board.get(Point::new(0, 0)), None);
+        assert_eq!(game.to_move, Stone::White);
+
+        game.undo().unwrap();
+        assert_eq!(game.board.get(Point::new(0, 0)), Some(Stone::White));
+        assert_eq!(game.board.get(Point::new(0, 1)), None);
+        assert_eq!(game.to_move, Stone::Black);
+        assert_eq!(game.black_captures, 0);
+    }
+
+    #[test]
+    fn test_undo_without_history_errors() {
+        let mut game = Game::new(9);
+        assert_eq!(game.undo(), Err("No moves to undo"));
+    }
+
+    fn two_walls() -> Game {
+        let mut game = Game::new(5);
+        for y in 0..5 {
+            game.board.place_stone(Point::new(1, y), Stone::Black).unwrap();
+            game.board.place_stone(Point::new(3, y), Stone::White).unwrap();
+        }
+        game
+    }
+
+    #[test]
+    fn test_two_passes_start_scoring() {
+        let mut game = Game::new(9);
+        assert_eq!(game.mark_dead(Point::new(0, 0)), Err("Dead stones can only be marked in the scoring phase"));
+        game.pass().unwrap();
+        game.play(Point::new(2, 2)).unwrap();
+        game.pass().unwrap();
+        assert!(!game.is_scoring());
+        game.pass().unwrap();
+        assert!(game.is_scoring());
+        assert_eq!(game.play(Point::new(3, 3)), Err("Game is in the scoring phase"));
+    }
+
+    #[test]
+    fn test_marking_dead_group_adjusts_territory() {
+        let mut game = two_walls();
+        // A hopeless white invader inside Black's area
+        game.board.place_stone(Point::new(0, 2), Stone::White).unwrap();
+        game.pass().unwrap();
+        game.pass().unwrap();
+        // Black's column is spoiled by the invader until it is marked dead
+        assert_eq!(game.score_with_komi(0.0), (5.0, 11.0));
+
+        game.mark_dead(Point::new(0, 2)).unwrap();
+        game.finalize_score(Some(0.0)).unwrap();
+        assert_eq!(game.result, GameResult::Finished { black_score: 10.0, white_score: 10.0 });
+        assert_eq!(game.board.get(Point::new(0, 2)), None);
+        assert_eq!(game.black_captures, 1);
+    }
+
+    #[test]
+    fn test_cannot_undo_after_finalizing_score() {
+        let mut game = two_walls();
+        game.board.place_stone(Point::new(0, 2), Stone::White).unwrap();
+        game.pass().unwrap();
+        game.pass().unwrap();
+        game.mark_dead(Point::new(0, 2)).unwrap();
+        game.finalize_score(Some(0.0)).unwrap();
+
+        assert_eq!(game.undo(), Err("Game is already over"));
+        assert_eq!(game.board.get(Point::new(0, 2)), None);
+        assert_eq!(game.black_captures, 1);
+        assert_eq!(game.result, GameResult::Finished { black_score: 10.0, white_score: 10.0 });
+    }
+
+    #[test]
+    fn test_default_komi() {
+        let game = Game::new(19);
+        assert!((game.komi - 6.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_komi_decides_final_score() {
+        let mut game = two_walls();
+        game.komi = 0.5;
+        game.pass().unwrap();
+        game.pass().unwrap();
+        game.finalize_score(None).unwrap();
+        assert_eq!(game.result, GameResult::Finished { black_score: 10.0, white_score: 10.5 });
+
+        let mut game = Game::new_with_komi(5, 7.5);
+        assert!((game.komi - 7.5).abs() < f32::EPSILON);
+        game.board = two_walls().board;
+        assert_eq!(game.score(), (10.0, 17.5));
+    }
+
+    #[test]
+    fn test_is_legal() {
+        let mut game = Game::new(9);
+        game.play(Point::new(4, 4)).unwrap();
+        assert!(!game.is_legal(Point::new(4, 4)), "occupied");
+        assert!(game.is_legal(Point::new(0, 0)), "ordinary empty point");
+        assert!(!game.is_legal(Point::new(9, 0)), "off the board");
+    }
+
+    #[test]
+    fn test_is_legal_rejects_ko_recapture() {
+        let mut game = Game::new(9);
+        let moves = [(1, 0), (2, 0), (0, 1), (3, 1), (1, 2), (2, 2), (5, 5), (1, 1), (2, 1)];
+        for (x, y) in moves {
+            game.play(Point::new(x, y)).unwrap();
+        }
+        assert!(!game.is_legal(Point::new(1, 1)));
+        assert!(game.is_legal(Point::new(6, 6)));
+    }
+
+    #[test]
+    fn test_suicide_is_illegal() {
+        let mut game = Game::new(9);
+        // Black surrounds the corner point (0, 0)
+        for (x, y) in [(1, 0), (8, 8), (0, 1)] {
+            game.play(Point::new(x, y)).unwrap();
+        }
+        assert!(!game.is_legal(Point::new(0, 0)));
+        assert_eq!(game.play(Point::new(0, 0)), Err("Suicide is not allowed"));
+        assert_eq!(game.to_move, Stone::White);
+    }
+
+    fn play_ko_capture() -> Game {
+        let mut game = Game::new(9);
+        // Black at (2, 1) captures White at (1, 1), leaving a ko
+        let moves = [(1, 0), (2, 0), (0, 1), (3, 1), (1, 2), (2, 2), (5, 5), (1, 1), (2, 1)];
+        for (x, y) in moves {
+            game.play(Point::new(x, y)).unwrap();
+        }
+        game
+    }
+
+    #[test]
+    fn test_superko_rejects_repeated_position() {
+        let mut game = play_ko_capture();
+        let hash = game.board.hash();
+        // Clear the simple ko point to show the hash history alone catches the repeat
+        game.ko = None;
+        assert!(!game.is_legal(Point::new(1, 1)));
+        assert_eq!(game.play(Point::new(1, 1)), Err("Move violates the superko rule"));
+        assert_eq!(game.board.hash(), hash);
+        assert_eq!(game.to_move, Stone::White);
+    }
+
+    #[test]
+    fn test_superko_allows_new_positions_and_undo() {
+        let mut game = play_ko_capture();
+        game.play(Point::new(7, 7)).unwrap();
+        game.pass().unwrap();
+        let before_retake = game.board.hash();
+        // With an extra White stone on the board, retaking reaches a new position
+        game.play(Point::new(1, 1)).unwrap();
+        assert_eq!(game.board.get(Point::new(1, 1)), Some(Stone::White));
+        assert_eq!(game.board.get(Point::new(2, 1)), None);
+        assert_eq!(game.to_move, Stone::Black);
+        assert_eq!(game.white_captures, 1);
+        let after_retake = game.board.hash();
+
+        game.undo().unwrap();
+        assert_eq!(game.board.hash(), before_retake);
+        assert_eq!(game.board.get(Point::new(2, 1)), Some(Stone::Black));
+        assert_eq!(game.to_move, Stone::White);
+        assert_eq!(game.white_captures, 0);
+
+        // Undo forgets the retaken position, so playing it again is still legal
+        game.play(Point::new(1, 1)).unwrap();
+        assert_eq!(game.board.hash(), after_retake);
+        assert_eq!(game.to_move, Stone::Black);
+    }
 }
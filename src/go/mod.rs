@@ -10,6 +10,20 @@
 //! - Ko rule enforcement
 //! - Game end detection
 //! - Score calculation
+//!
+//! ## Example
+//! ```
+//! use puzzle_engine::go::{Game, GameResult, Point, Stone};
+//! let mut game = Game::new(9);
+//! game.play(Point::new(4, 4)).unwrap();
+//! assert_eq!(game.board.get(Point::new(4, 4)), Some(Stone::Black));
+//! assert_eq!(game.result, GameResult::Ongoing);
+//! ```
 
+pub mod ai;
 pub mod board;
-pub mod game;
\ No newline at end of file
+pub mod game;
+
+pub use board::{Board, Point, Stone};
+pub use game::{Game, GameResult};
+
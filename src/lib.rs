@@ -1,5 +1,13 @@
 #![warn(clippy::all, clippy::pedantic)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod maze;
 pub mod cipher;
+#[cfg(feature = "std")]
 pub mod chess;
-pub mod go;
\ No newline at end of file
+#[cfg(feature = "std")]
+pub mod go;
+pub mod prelude;
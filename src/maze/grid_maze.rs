@@ -1,5 +1,12 @@
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use std::collections::{HashSet, VecDeque};
+use rand::{Rng, SeedableRng};
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+/// Maximum number of mazes generated by [`Maze::new_with_min_path`] before giving up.
+const MAX_GENERATION_ATTEMPTS: u64 = 100;
 
 /// Represents a 2D position in the maze grid.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -8,15 +15,60 @@ pub struct Position {
     pub y: usize,
 }
 
-/// Cardinal directions used to move within the maze.
-#[derive(Clone, Copy, PartialEq, Eq)]
+/// Directions used to move within the maze.
+///
+/// Diagonal directions are only usable in mazes generated with [`Maze::new_with_diagonals`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Direction {
     North,
     South,
     East,
     West,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
+}
+
+impl Direction {
+    /// The change in `(x, y)` from taking one step this way; north is towards `y = 0`.
+    fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::South => (0, 1),
+            Direction::East => (1, 0),
+            Direction::West => (-1, 0),
+            Direction::NorthEast => (1, -1),
+            Direction::NorthWest => (-1, -1),
+            Direction::SouthEast => (1, 1),
+            Direction::SouthWest => (-1, 1),
+        }
+    }
 }
 
+/// Number of bytes before the wall bitmap in [`Maze::to_bytes`]: six `u64` fields and a flag byte.
+const HEADER_LEN: usize = 6 * 8 + 1;
+
+/// Directions whose passages are stored for each cell by [`Maze::to_bytes`]. Every other
+/// passage is the reverse of one of these from a neighbouring cell. Only the first two are
+/// used for mazes without diagonals.
+const STORED_DIRECTIONS: [Direction; 4] = [Direction::East, Direction::South, Direction::SouthEast, Direction::SouthWest];
+
+/// The four orthogonal directions.
+const ORTHOGONAL: [Direction; 4] = [Direction::North, Direction::South, Direction::East, Direction::West];
+
+/// All eight directions, orthogonal and diagonal.
+const ALL_DIRECTIONS: [Direction; 8] = [
+    Direction::North,
+    Direction::South,
+    Direction::East,
+    Direction::West,
+    Direction::NorthEast,
+    Direction::NorthWest,
+    Direction::SouthEast,
+    Direction::SouthWest,
+];
+
 /// A maze generated using randomized DFS, with support for traversal.
 #[derive(Clone)]
 pub struct Maze {
@@ -26,6 +78,13 @@ pub struct Maze {
     connections: HashSet<(Position, Position)>,
     start: Position,
     end: Position,
+    exits: Vec<Position>,
+    diagonals: bool,
+    // Whether the edges wrap around, making the grid a torus
+    wrapping: bool,
+    // How strongly generation prefers to keep carving in the same direction
+    straightness: f32,
+    history: Vec<Direction>,
     /// The current position of the player within the maze.
     pub player: Position,
 }
@@ -45,32 +104,219 @@ impl Maze {
     /// let maze = puzzle_engine::maze::grid_maze::Maze::new(5, 5);
     /// assert_eq!(maze.player,puzzle_engine::maze::grid_maze::Position { x: 0, y: 0 });
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` or `height` is zero. Use [`Maze::try_new`] to handle that case.
     pub fn new(width: usize, height: usize) -> Self {
+        let mut maze = Self::empty(width, height);
+        maze.generate_iterative(&mut rand::rng());
+        maze
+    }
+
+    /// Creates a new maze like [`Maze::new`], rejecting empty dimensions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `width` or `height` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::Maze;
+    /// assert!(Maze::try_new(0, 5).is_err());
+    /// assert_eq!(Maze::try_new(1, 1).unwrap().solve().unwrap().len(), 1);
+    /// ```
+    pub fn try_new(width: usize, height: usize) -> Result<Maze, String> {
+        if width == 0 || height == 0 {
+            return Err(format!("Maze dimensions must be non-zero, got {width}x{height}"));
+        }
+        Ok(Self::new(width, height))
+    }
+
+    /// Creates a new maze whose layout is fully determined by `seed`.
+    ///
+    /// The same dimensions and seed always produce the same maze.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::Maze;
+    /// let a = Maze::new_seeded(6, 6, 42);
+    /// let b = Maze::new_seeded(6, 6, 42);
+    /// assert_eq!(a.solve(), b.solve());
+    /// ```
+    #[must_use]
+    pub fn new_seeded(width: usize, height: usize, seed: u64) -> Self {
+        let mut maze = Self::empty(width, height);
+        maze.generate_iterative(&mut StdRng::seed_from_u64(seed));
+        maze
+    }
+
+    /// Creates a seeded maze whose passages may also run diagonally.
+    ///
+    /// Diagonal passages are carved alongside orthogonal ones and can be walked with the
+    /// diagonal [`Direction`] variants.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::Maze;
+    /// let maze = Maze::new_with_diagonals(5, 5, 3);
+    /// assert!(maze.solve().is_some());
+    /// ```
+    #[must_use]
+    pub fn new_with_diagonals(width: usize, height: usize, seed: u64) -> Self {
+        let mut maze = Self::empty(width, height);
+        maze.diagonals = true;
+        maze.generate_iterative(&mut StdRng::seed_from_u64(seed));
+        maze
+    }
+
+    /// Creates a seeded maze on a torus: stepping off one edge of the grid re-enters from the
+    /// opposite edge, so passages may join the first and last rows or columns.
+    ///
+    /// Movement and every solver follow the wrapped passages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::Maze;
+    /// let maze = Maze::new_toroidal(6, 6, 2);
+    /// assert!(maze.solve().is_some());
+    /// ```
+    #[must_use]
+    pub fn new_toroidal(width: usize, height: usize, seed: u64) -> Self {
+        let mut maze = Self::empty(width, height);
+        maze.wrapping = true;
+        maze.generate_iterative(&mut StdRng::seed_from_u64(seed));
+        maze
+    }
+
+    /// Creates a seeded maze biased towards long straight corridors.
+    ///
+    /// Whenever generation reaches a cell, it carves straight on in the direction it arrived
+    /// from, before branching anywhere else, with probability `straightness / (1 + straightness)`.
+    /// A `straightness` of 0 gives the same unbiased maze as [`Maze::new_seeded`]; larger values
+    /// give longer straight runs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::Maze;
+    /// let maze = Maze::new_with_straightness(8, 8, 5, 4.0);
+    /// assert!(maze.solve().is_some());
+    /// assert_eq!(
+    ///     Maze::new_with_straightness(8, 8, 5, 0.0).solve(),
+    ///     Maze::new_seeded(8, 8, 5).solve(),
+    /// );
+    /// ```
+    #[must_use]
+    pub fn new_with_straightness(width: usize, height: usize, seed: u64, straightness: f32) -> Self {
+        let mut maze = Self::empty(width, height);
+        maze.straightness = straightness.max(0.0);
+        maze.generate_iterative(&mut StdRng::seed_from_u64(seed));
+        maze
+    }
+
+    /// Creates a seeded maze whose solution path visits at least `min_path_len` cells.
+    ///
+    /// Mazes are regenerated from successive seeds, starting at `seed`, until one meets the
+    /// target. If no maze does within a bounded number of attempts, the maze with the
+    /// longest solution found is returned instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::Maze;
+    /// let maze = Maze::new_with_min_path(8, 8, 7, 20);
+    /// assert!(maze.solve().unwrap().len() >= 20);
+    /// ```
+    #[must_use]
+    pub fn new_with_min_path(width: usize, height: usize, seed: u64, min_path_len: usize) -> Self {
+        let path_len = |maze: &Maze| maze.solve().map_or(0, |path| path.len());
+        let mut best = Self::new_seeded(width, height, seed);
+        let mut best_len = path_len(&best);
+        for attempt in 1..MAX_GENERATION_ATTEMPTS {
+            if best_len >= min_path_len {
+                break;
+            }
+            let maze = Self::new_seeded(width, height, seed.wrapping_add(attempt));
+            let len = path_len(&maze);
+            if len > best_len {
+                best = maze;
+                best_len = len;
+            }
+        }
+        best
+    }
+
+    /// Re-rolls the maze in place with a new layout determined by `seed`.
+    ///
+    /// The dimensions, start, exits and generation options are kept; every passage is carved
+    /// afresh, the move history is cleared and the player returns to the start.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::Maze;
+    /// let mut maze = Maze::new(6, 6);
+    /// maze.regenerate(11);
+    /// assert_eq!(maze.solve(), Maze::new_seeded(6, 6, 11).solve());
+    /// ```
+    pub fn regenerate(&mut self, seed: u64) {
+        self.visited.clear();
+        self.connections.clear();
+        self.history.clear();
+        self.player = self.start;
+        self.generate_iterative(&mut StdRng::seed_from_u64(seed));
+    }
+
+    /// Creates an ungenerated maze with every wall in place.
+    fn empty(width: usize, height: usize) -> Self {
+        assert!(width > 0 && height > 0, "maze dimensions must be non-zero");
         let start = Position { x: 0, y: 0 };
         let end = Position { x: width - 1, y: height - 1 };
-        let mut maze = Maze {
+        Maze {
             width,
             height,
             visited: HashSet::new(),
             connections: HashSet::new(),
             start,
             end,
+            exits: vec![end],
+            diagonals: false,
+            wrapping: false,
+            straightness: 0.0,
+            history: Vec::new(),
             player: start,
-        };
-        maze.generate_iterative();
-        maze
+        }
     }
 
     /// Internal function to generate the maze using iterative DFS (depth-first search).
-    fn generate_iterative(&mut self) {
-        let mut rng = rand::rng();
+    fn generate_iterative<R: Rng>(&mut self, rng: &mut R) {
         let mut stack = VecDeque::new();
-        stack.push_back(self.start);
+        stack.push_back((self.start, None));
         self.visited.insert(self.start);
+        let keep_straight = self.straightness / (1.0 + self.straightness);
+
+        while let Some((pos, entered)) = stack.pop_back() {
+            // Possibly carve straight on alone, coming back to this cell's other neighbours later
+            let straight_on = entered
+                .filter(|_| keep_straight > 0.0 && rng.random::<f32>() < keep_straight)
+                .and_then(|dir| Some((dir, self.move_pos(pos, dir)?)))
+                .filter(|(_, next_pos)| !self.visited.contains(next_pos));
+            if let Some((dir, next_pos)) = straight_on {
+                self.connections.insert((pos, next_pos));
+                self.connections.insert((next_pos, pos));
+                self.visited.insert(next_pos);
+                stack.push_back((pos, None));
+                stack.push_back((next_pos, Some(dir)));
+                continue;
+            }
 
-        while let Some(pos) = stack.pop_back() {
-            let mut directions = [Direction::North, Direction::South, Direction::East, Direction::West];
-            directions.shuffle(&mut rng);
+            let mut directions = if self.diagonals { ALL_DIRECTIONS.to_vec() } else { ORTHOGONAL.to_vec() };
+            directions.shuffle(rng);
 
             for dir in directions {
                 if let Some(next_pos) = self.move_pos(pos, dir) {
@@ -78,21 +324,26 @@ impl Maze {
                         self.connections.insert((pos, next_pos));
                         self.connections.insert((next_pos, pos));
                         self.visited.insert(next_pos);
-                        stack.push_back(next_pos);
+                        stack.push_back((next_pos, Some(dir)));
                     }
                 }
             }
         }
     }
     /// internal function that returns the new position if moving from a given position in a certain direction is valid.
+    ///
+    /// In toroidal mazes steps off an edge wrap around, unless they would lead back to `pos` itself.
     fn move_pos(&self, pos: Position, dir: Direction) -> Option<Position> {
-        match dir {
-            Direction::North if pos.y > 0 => Some(Position { x: pos.x, y: pos.y - 1 }),
-            Direction::South if pos.y < self.height - 1 => Some(Position { x: pos.x, y: pos.y + 1 }),
-            Direction::East if pos.x < self.width - 1 => Some(Position { x: pos.x + 1, y: pos.y }),
-            Direction::West if pos.x > 0 => Some(Position { x: pos.x - 1, y: pos.y }),
-            _ => None,
-        }
+        let (dx, dy) = dir.offset();
+        let step = |value: usize, delta: isize, len: usize| {
+            if self.wrapping {
+                (value + len).checked_add_signed(delta).map(|next| next % len)
+            } else {
+                value.checked_add_signed(delta).filter(|&next| next < len)
+            }
+        };
+        let next = Position { x: step(pos.x, dx, self.width)?, y: step(pos.y, dy, self.height)? };
+        (next != pos).then_some(next)
     }
 
     /// Attempts to move the player in the given direction if there is a path.
@@ -116,17 +367,51 @@ impl Maze {
         if let Some(new_pos) = self.move_pos(self.player, dir) {
             if self.connections.contains(&(self.player, new_pos)) {
                 self.player = new_pos;
+                self.history.push(dir);
                 return true;
             }
         }
         false
     }
 
+    /// Returns every successful move made with [`Maze::try_move`], oldest first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::{Direction, Maze};
+    /// let mut maze = Maze::new(1, 3);
+    /// maze.try_move(Direction::South);
+    /// maze.try_move(Direction::East);
+    /// assert_eq!(maze.move_history(), &[Direction::South]);
+    /// ```
+    #[must_use]
+    pub fn move_history(&self) -> &[Direction] {
+        &self.history
+    }
+
+    /// Returns the player to the start and clears the move history.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::{Direction, Maze, Position};
+    /// let mut maze = Maze::new(1, 3);
+    /// maze.try_move(Direction::South);
+    /// maze.reset_player();
+    /// assert_eq!(maze.player, Position { x: 0, y: 0 });
+    /// assert!(maze.move_history().is_empty());
+    /// ```
+    pub fn reset_player(&mut self) {
+        self.player = self.start;
+        self.history.clear();
+    }
+
     /// Checks if the player has reached the end of the maze.
     ///
     /// # Returns
     ///
-    /// `true` if the player's current position is the end position.
+    /// `true` if the player's current position is the end position or any exit added with [`Maze::add_exit`].
     ///
     /// # Examples
     ///
@@ -139,7 +424,381 @@ impl Maze {
     /// }
     /// ```
     pub fn is_at_end(&self) -> bool {
-        self.player == self.end
+        self.exits.contains(&self.player)
+    }
+
+    /// Registers an additional exit. Reaching any exit counts as solving the maze.
+    ///
+    /// The original end position is always an exit. Positions outside the maze are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `pos` - Position of the new exit
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::{Maze, Position};
+    /// let mut maze = Maze::new(4, 4);
+    /// maze.add_exit(Position { x: 3, y: 0 });
+    /// maze.player = Position { x: 3, y: 0 };
+    /// assert!(maze.is_at_end());
+    /// ```
+    pub fn add_exit(&mut self, pos: Position) {
+        if pos.x < self.width && pos.y < self.height && !self.exits.contains(&pos) {
+            self.exits.push(pos);
+        }
+    }
+
+    /// Finds the shortest path from the start to the end using BFS.
+    ///
+    /// # Returns
+    ///
+    /// `Some(path)` listing every cell from start to end inclusive, or `None` if the end is unreachable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::{Maze, Position};
+    /// let maze = Maze::new(4, 4);
+    /// let path = maze.solve().unwrap();
+    /// assert_eq!(path.first(), Some(&Position { x: 0, y: 0 }));
+    /// assert_eq!(path.last(), Some(&Position { x: 3, y: 3 }));
+    /// ```
+    #[must_use]
+    pub fn solve(&self) -> Option<Vec<Position>> {
+        self.shortest_path_to(|pos| pos == self.end)
+    }
+
+    /// Finds the shortest route from the start to the end as the directions to walk.
+    ///
+    /// Passing each direction to [`Maze::try_move`] in turn, starting from the start, leads
+    /// the player to the end.
+    ///
+    /// # Returns
+    ///
+    /// `Some(directions)`, empty if the start is the end, or `None` if the end is unreachable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::{Direction, Maze};
+    /// let maze = Maze::new(1, 3);
+    /// assert_eq!(maze.solve_directions(), Some(vec![Direction::South, Direction::South]));
+    /// ```
+    #[must_use]
+    pub fn solve_directions(&self) -> Option<Vec<Direction>> {
+        let path = self.solve()?;
+        path.windows(2)
+            .map(|step| ALL_DIRECTIONS.into_iter().find(|&dir| self.move_pos(step[0], dir) == Some(step[1])))
+            .collect()
+    }
+
+    /// Returns the number of steps on the shortest route from the start to the end.
+    ///
+    /// This is one less than the length of [`Maze::solve`]'s path, found with a BFS that
+    /// only tracks distances rather than building the path.
+    ///
+    /// # Returns
+    ///
+    /// `Some(steps)`, or `None` if the end is unreachable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::Maze;
+    /// let maze = Maze::new(1, 4);
+    /// assert_eq!(maze.shortest_path_len(), Some(3));
+    /// ```
+    #[must_use]
+    pub fn shortest_path_len(&self) -> Option<usize> {
+        let mut distances = HashMap::from([(self.start, 0)]);
+        let mut queue = VecDeque::from([self.start]);
+        while let Some(current) = queue.pop_front() {
+            let distance = distances[&current];
+            if current == self.end {
+                return Some(distance);
+            }
+            for next in self.open_neighbors(current) {
+                if let Entry::Vacant(entry) = distances.entry(next) {
+                    entry.insert(distance + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Finds the shortest path from the start to the nearest exit using BFS.
+    ///
+    /// # Returns
+    ///
+    /// `Some(path)` from start to the closest exit inclusive, or `None` if no exit is reachable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::Maze;
+    /// let mut maze = Maze::new(4, 4);
+    /// let to_end = maze.solve().unwrap();
+    /// maze.add_exit(to_end[1]);
+    /// assert_eq!(maze.solve_any().unwrap().len(), 2);
+    /// ```
+    #[must_use]
+    pub fn solve_any(&self) -> Option<Vec<Position>> {
+        self.shortest_path_to(|pos| self.exits.contains(&pos))
+    }
+
+    /// Finds the shortest path from the start to the end using A* search.
+    ///
+    /// The heuristic is the Manhattan distance to the end (Chebyshev distance when
+    /// diagonal passages are enabled, so the estimate never overshoots). In toroidal mazes
+    /// each axis distance is measured the shorter way around.
+    ///
+    /// # Returns
+    ///
+    /// `Some(path)` listing every cell from start to end inclusive, or `None` if the end is unreachable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::Maze;
+    /// let maze = Maze::new_seeded(8, 8, 5);
+    /// assert_eq!(maze.solve_astar().unwrap().len(), maze.solve().unwrap().len());
+    /// ```
+    #[must_use]
+    pub fn solve_astar(&self) -> Option<Vec<Position>> {
+        let heuristic = |pos: Position| {
+            let axis = |a: usize, b: usize, len: usize| {
+                let direct = a.abs_diff(b);
+                if self.wrapping { direct.min(len - direct) } else { direct }
+            };
+            let dx = axis(pos.x, self.end.x, self.width);
+            let dy = axis(pos.y, self.end.y, self.height);
+            if self.diagonals { dx.max(dy) } else { dx + dy }
+        };
+
+        let mut came_from: HashMap<Position, Position> = HashMap::new();
+        let mut best_cost: HashMap<Position, usize> = HashMap::new();
+        let mut open = BinaryHeap::new();
+        best_cost.insert(self.start, 0);
+        open.push(Reverse((heuristic(self.start), 0, self.start.x, self.start.y)));
+
+        while let Some(Reverse((_, cost, x, y))) = open.pop() {
+            let current = Position { x, y };
+            if current == self.end {
+                let mut path = vec![current];
+                while let Some(&prev) = came_from.get(&path[path.len() - 1]) {
+                    path.push(prev);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            if cost > best_cost[&current] {
+                continue; // stale queue entry
+            }
+            for next in self.open_neighbors(current) {
+                let next_cost = cost + 1;
+                if best_cost.get(&next).is_none_or(|&known| next_cost < known) {
+                    best_cost.insert(next, next_cost);
+                    came_from.insert(next, current);
+                    open.push(Reverse((next_cost + heuristic(next), next_cost, next.x, next.y)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Computes the BFS distance from the start to every reachable cell.
+    ///
+    /// Useful for heatmaps, difficulty metrics, or picking the farthest cell as an alternative goal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::{Maze, Position};
+    /// let maze = Maze::new(4, 4);
+    /// let distances = maze.distances_from_start();
+    /// assert_eq!(distances[&Position { x: 0, y: 0 }], 0);
+    /// assert_eq!(distances.len(), 16);
+    /// ```
+    #[must_use]
+    pub fn distances_from_start(&self) -> HashMap<Position, usize> {
+        let mut distances = HashMap::new();
+        let mut queue = VecDeque::new();
+        distances.insert(self.start, 0);
+        queue.push_back(self.start);
+
+        while let Some(current) = queue.pop_front() {
+            let next_distance = distances[&current] + 1;
+            for next in self.open_neighbors(current) {
+                if let Entry::Vacant(entry) = distances.entry(next) {
+                    entry.insert(next_distance);
+                    queue.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+
+    /// Serializes the maze into a compact byte form that [`Maze::from_bytes`] reads back.
+    ///
+    /// The encoding is the width, height, start and end as little-endian `u64`s, a flag byte
+    /// marking diagonal (bit 0) and toroidal (bit 1) mazes, then a bitmap with one bit per
+    /// passage leading east or south of each cell (plus south-east and south-west for diagonal
+    /// mazes). Extra exits added with [`Maze::add_exit`] and the player position are not stored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::Maze;
+    /// let maze = Maze::new_seeded(10, 10, 1);
+    /// // 49 header bytes plus two bits for each of the 100 cells
+    /// assert_eq!(maze.to_bytes().len(), 49 + 25);
+    /// ```
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN);
+        for value in [self.width, self.height, self.start.x, self.start.y, self.end.x, self.end.y] {
+            bytes.extend_from_slice(&(value as u64).to_le_bytes());
+        }
+        bytes.push(u8::from(self.diagonals) | u8::from(self.wrapping) << 1);
+
+        let directions = self.stored_directions();
+        let mut bitmap = vec![0u8; (self.width * self.height * directions.len()).div_ceil(8)];
+        let mut bit = 0;
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let pos = Position { x, y };
+                for dir in directions {
+                    let open = self
+                        .move_pos(pos, *dir)
+                        .is_some_and(|next| self.connections.contains(&(pos, next)));
+                    if open {
+                        bitmap[bit / 8] |= 1 << (bit % 8);
+                    }
+                    bit += 1;
+                }
+            }
+        }
+        bytes.extend(bitmap);
+        bytes
+    }
+
+    /// Rebuilds a maze from the output of [`Maze::to_bytes`], with the player at the start.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data is truncated or has trailing bytes, or if the dimensions,
+    /// start or end are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use puzzle_engine::maze::grid_maze::Maze;
+    /// let maze = Maze::new_seeded(6, 4, 9);
+    /// let copy = Maze::from_bytes(&maze.to_bytes()).unwrap();
+    /// assert_eq!(copy.solve(), maze.solve());
+    /// assert!(Maze::from_bytes(&[1, 2, 3]).is_err());
+    /// ```
+    pub fn from_bytes(data: &[u8]) -> Result<Maze, String> {
+        if data.len() < HEADER_LEN {
+            return Err("Maze data is too short.".to_string());
+        }
+        let mut fields = [0usize; 6];
+        for (field, chunk) in fields.iter_mut().zip(data[..HEADER_LEN - 1].chunks_exact(8)) {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(chunk);
+            let value = u64::from_le_bytes(buf);
+            *field = usize::try_from(value).map_err(|_| "Maze dimension is too large.".to_string())?;
+        }
+        let [width, height, start_x, start_y, end_x, end_y] = fields;
+        if width == 0 || height == 0 {
+            return Err("Maze must have at least one cell.".to_string());
+        }
+        if start_x >= width || start_y >= height || end_x >= width || end_y >= height {
+            return Err("Start and end must lie inside the maze.".to_string());
+        }
+        let flags = data[HEADER_LEN - 1];
+        if flags > 0b11 {
+            return Err(format!("Unknown maze flags: {flags}."));
+        }
+
+        let mut maze = Self::empty(width, height);
+        maze.diagonals = flags & 1 != 0;
+        maze.wrapping = flags & 0b10 != 0;
+        maze.start = Position { x: start_x, y: start_y };
+        maze.end = Position { x: end_x, y: end_y };
+        maze.exits = vec![maze.end];
+        maze.player = maze.start;
+
+        let directions = maze.stored_directions();
+        let bitmap = &data[HEADER_LEN..];
+        let cells = width
+            .checked_mul(height)
+            .and_then(|cells| cells.checked_mul(directions.len()))
+            .ok_or("Maze dimension is too large.")?;
+        if bitmap.len() != cells.div_ceil(8) {
+            return Err("Maze data has the wrong length.".to_string());
+        }
+        let mut bit = 0;
+        for y in 0..height {
+            for x in 0..width {
+                let pos = Position { x, y };
+                maze.visited.insert(pos);
+                for dir in directions {
+                    if bitmap[bit / 8] & (1 << (bit % 8)) != 0 {
+                        let next = maze.move_pos(pos, *dir).ok_or("Maze data has a passage leading off the grid.")?;
+                        maze.connections.insert((pos, next));
+                        maze.connections.insert((next, pos));
+                    }
+                    bit += 1;
+                }
+            }
+        }
+        Ok(maze)
+    }
+
+    /// Internal function returning the passage directions stored per cell by [`Maze::to_bytes`].
+    fn stored_directions(&self) -> &'static [Direction] {
+        if self.diagonals { &STORED_DIRECTIONS } else { &STORED_DIRECTIONS[..2] }
+    }
+
+    /// Internal BFS from the start to the first cell satisfying `is_goal`.
+    fn shortest_path_to<F: Fn(Position) -> bool>(&self, is_goal: F) -> Option<Vec<Position>> {
+        let mut came_from: HashMap<Position, Position> = HashMap::new();
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::new();
+        visited.insert(self.start);
+        queue.push_back(self.start);
+
+        while let Some(current) = queue.pop_front() {
+            if is_goal(current) {
+                let mut path = vec![current];
+                while let Some(&prev) = came_from.get(&path[path.len() - 1]) {
+                    path.push(prev);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for next in self.open_neighbors(current) {
+                if visited.insert(next) {
+                    came_from.insert(next, current);
+                    queue.push_back(next);
+                }
+            }
+        }
+        None
+    }
+
+    /// Internal function returning the cells reachable from `pos` in a single step.
+    fn open_neighbors(&self, pos: Position) -> Vec<Position> {
+        let directions: &[Direction] = if self.diagonals { &ALL_DIRECTIONS } else { &ORTHOGONAL };
+        directions
+            .iter()
+            .filter_map(|dir| self.move_pos(pos, *dir))
+            .filter(|next| self.connections.contains(&(pos, *next)))
+            .collect()
     }
 }
 
@@ -153,6 +812,17 @@ mod tests {
         assert_eq!(maze.player, Position { x: 0, y: 0 });
     }
 
+    #[test]
+    fn test_try_new_rejects_zero_dimensions() {
+        assert!(Maze::try_new(0, 5).is_err());
+        assert!(Maze::try_new(5, 0).is_err());
+        for (width, height) in [(1, 1), (1, 2), (2, 1), (3, 3)] {
+            let maze = Maze::try_new(width, height).unwrap();
+            assert_eq!(maze.end, Position { x: width - 1, y: height - 1 });
+            assert!(maze.solve().is_some());
+        }
+    }
+
     #[test]
     fn test_move_pos_bounds() {
         let maze = Maze::new(3, 3);
@@ -184,6 +854,314 @@ mod tests {
         assert!(maze.is_at_end());
     }
 
+    #[test]
+    fn test_seeded_maze_is_deterministic() {
+        let a = Maze::new_seeded(6, 5, 1234);
+        let b = Maze::new_seeded(6, 5, 1234);
+        assert_eq!(a.connections, b.connections);
+    }
+
+    #[test]
+    fn test_shortest_path_len_matches_solve() {
+        for seed in 0..5 {
+            let maze = Maze::new_seeded(9, 7, seed);
+            assert_eq!(maze.shortest_path_len(), maze.solve().map(|p| p.len() - 1));
+        }
+        let maze = Maze::new_with_diagonals(6, 6, 4);
+        assert_eq!(maze.shortest_path_len(), maze.solve().map(|p| p.len() - 1));
+
+        let mut walled = Maze::new_seeded(3, 3, 1);
+        walled.connections.clear();
+        assert_eq!(walled.shortest_path_len(), None);
+    }
+
+    /// Average number of passages in each maximal straight run of the maze.
+    fn average_straight_run(maze: &Maze) -> f64 {
+        let mut passages = 0;
+        let mut runs = 0;
+        for &(from, to) in &maze.connections {
+            let Some(dir) = ORTHOGONAL.into_iter().find(|&dir| maze.move_pos(from, dir) == Some(to)) else {
+                continue;
+            };
+            passages += 1;
+            // A run starts at a passage not continuing one in the same direction
+            let opposite = match dir {
+                Direction::North => Direction::South,
+                Direction::South => Direction::North,
+                Direction::East => Direction::West,
+                _ => Direction::East,
+            };
+            let continues = maze
+                .move_pos(from, opposite)
+                .is_some_and(|prev| maze.connections.contains(&(prev, from)));
+            if !continues {
+                runs += 1;
+            }
+        }
+        f64::from(passages) / f64::from(runs)
+    }
+
+    #[test]
+    fn test_straightness_lengthens_straight_runs() {
+        let (mut unbiased, mut straight) = (0.0, 0.0);
+        for seed in 0..5 {
+            unbiased += average_straight_run(&Maze::new_with_straightness(20, 20, seed, 0.0));
+            straight += average_straight_run(&Maze::new_with_straightness(20, 20, seed, 10.0));
+        }
+        assert!(straight > unbiased * 1.2, "straight {straight} vs unbiased {unbiased}");
+        for seed in 0..3 {
+            let a = Maze::new_with_straightness(10, 10, seed, 0.0);
+            assert_eq!(a.connections, Maze::new_seeded(10, 10, seed).connections);
+            assert!(Maze::new_with_straightness(10, 10, seed, 10.0).solve().is_some());
+        }
+    }
+
+    #[test]
+    fn test_solve_reaches_end() {
+        let maze = Maze::new_seeded(7, 7, 3);
+        let path = maze.solve().unwrap();
+        assert_eq!(path.first(), Some(&maze.start));
+        assert_eq!(path.last(), Some(&maze.end));
+        for pair in path.windows(2) {
+            assert!(maze.connections.contains(&(pair[0], pair[1])));
+        }
+    }
+
+    #[test]
+    fn test_new_with_min_path_meets_target() {
+        let target = 25;
+        let maze = Maze::new_with_min_path(8, 8, 99, target);
+        assert!(maze.solve().unwrap().len() >= target);
+    }
+
+    #[test]
+    fn test_new_with_min_path_unreachable_target_returns_longest() {
+        let maze = Maze::new_with_min_path(3, 3, 5, 1_000);
+        let len = maze.solve().unwrap().len();
+        assert!(len <= 9);
+        for attempt in 0..MAX_GENERATION_ATTEMPTS {
+            let other = Maze::new_seeded(3, 3, 5 + attempt);
+            assert!(other.solve().unwrap().len() <= len);
+        }
+    }
+
+    #[test]
+    fn test_secondary_exit_counts_as_end() {
+        let mut maze = Maze::new_seeded(5, 5, 11);
+        let exit = Position { x: 4, y: 0 };
+        maze.add_exit(exit);
+        maze.player = exit;
+        assert!(maze.is_at_end());
+        maze.player = maze.end;
+        assert!(maze.is_at_end(), "The original end remains an exit.");
+        maze.player = Position { x: 2, y: 2 };
+        assert!(!maze.is_at_end());
+    }
+
+    #[test]
+    fn test_solve_any_targets_closer_exit() {
+        let mut maze = Maze::new_seeded(6, 6, 8);
+        let to_end = maze.solve().unwrap();
+        assert!(to_end.len() > 3);
+        let near_exit = to_end[2];
+        maze.add_exit(near_exit);
+
+        let path = maze.solve_any().unwrap();
+        assert_eq!(path.last(), Some(&near_exit));
+        assert_eq!(path.len(), 3);
+        assert_eq!(maze.solve(), Some(to_end), "solve still targets the original end");
+    }
+
+    #[test]
+    fn test_add_exit_ignores_out_of_bounds() {
+        let mut maze = Maze::new(3, 3);
+        maze.add_exit(Position { x: 3, y: 0 });
+        assert_eq!(maze.exits, vec![maze.end]);
+    }
+
+    #[test]
+    fn test_distances_from_start() {
+        let maze = Maze::new_seeded(6, 4, 21);
+        let distances = maze.distances_from_start();
+        assert_eq!(distances[&maze.start], 0);
+        for neighbor in maze.open_neighbors(maze.start) {
+            assert_eq!(distances[&neighbor], 1);
+        }
+        assert_eq!(distances.len(), 24, "Every cell of a perfect maze is reachable.");
+        assert_eq!(distances[&maze.end], maze.solve().unwrap().len() - 1);
+    }
+
+    #[test]
+    fn test_default_generation_is_orthogonal() {
+        let maze = Maze::new_seeded(6, 6, 4);
+        for (a, b) in &maze.connections {
+            assert_eq!(a.x.abs_diff(b.x) + a.y.abs_diff(b.y), 1);
+        }
+    }
+
+    #[test]
+    fn test_diagonal_move_follows_carved_connection() {
+        let mut maze = Maze::new_with_diagonals(6, 6, 17);
+        let &(from, to) = maze
+            .connections
+            .iter()
+            .find(|(a, b)| b.x == a.x + 1 && b.y == a.y + 1)
+            .expect("a south-east passage is carved");
+        assert!(maze.connections.contains(&(to, from)), "Diagonal connections are symmetric.");
+
+        maze.player = from;
+        assert!(maze.try_move(Direction::SouthEast));
+        assert_eq!(maze.player, to);
+        assert!(maze.try_move(Direction::NorthWest));
+        assert_eq!(maze.player, from);
+    }
+
+    #[test]
+    fn test_diagonal_move_fails_without_connection() {
+        let mut maze = Maze::new_with_diagonals(6, 6, 17);
+        let from = (0..6)
+            .flat_map(|x| (0..5).map(move |y| Position { x, y }))
+            .find(|p| p.x > 0 && !maze.connections.contains(&(*p, Position { x: p.x - 1, y: p.y + 1 })))
+            .expect("some south-west wall exists");
+
+        maze.player = from;
+        assert!(!maze.try_move(Direction::SouthWest));
+        assert_eq!(maze.player, from);
+        maze.player = Position { x: 0, y: 0 };
+        assert!(!maze.try_move(Direction::NorthWest), "Cannot leave the grid diagonally.");
+    }
+
+    #[test]
+    fn test_solve_astar_matches_bfs_length() {
+        for seed in 0..10 {
+            let maze = Maze::new_seeded(9, 7, seed);
+            let bfs = maze.solve().unwrap();
+            let astar = maze.solve_astar().unwrap();
+            assert_eq!(astar.len(), bfs.len());
+            assert_eq!(astar.first(), Some(&maze.start));
+            assert_eq!(astar.last(), Some(&maze.end));
+        }
+    }
+
+    #[test]
+    fn test_solve_astar_with_diagonals_matches_bfs_length() {
+        for seed in 0..10 {
+            let maze = Maze::new_with_diagonals(7, 7, seed);
+            assert_eq!(maze.solve_astar().unwrap().len(), maze.solve().unwrap().len());
+        }
+    }
+
+    #[test]
+    fn test_move_history_records_successful_moves() {
+        let mut maze = Maze::new_seeded(6, 6, 17);
+        let path = maze.solve().unwrap();
+        let mut expected = Vec::new();
+        for pair in path.windows(2) {
+            let dir = ORTHOGONAL
+                .into_iter()
+                .find(|&dir| maze.move_pos(pair[0], dir) == Some(pair[1]))
+                .unwrap();
+            // A blocked move first, which must not be logged
+            let blocked = ORTHOGONAL.into_iter().find(|&dir| {
+                maze.move_pos(maze.player, dir)
+                    .is_some_and(|next| !maze.connections.contains(&(maze.player, next)))
+            });
+            if let Some(blocked) = blocked {
+                assert!(!maze.try_move(blocked));
+            }
+            assert!(maze.try_move(dir));
+            expected.push(dir);
+        }
+        assert_eq!(maze.move_history(), expected.as_slice());
+        assert!(maze.is_at_end());
+
+        maze.reset_player();
+        assert_eq!(maze.player, maze.start);
+        assert!(maze.move_history().is_empty());
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let maze = Maze::new_seeded(9, 6, 21);
+        let mut copy = Maze::from_bytes(&maze.to_bytes()).unwrap();
+        assert_eq!(copy.connections, maze.connections);
+        assert_eq!((copy.start, copy.end), (maze.start, maze.end));
+        assert_eq!(copy.solve().map(|path| path.len()), maze.solve().map(|path| path.len()));
+
+        copy.player = Position { x: 3, y: 3 };
+        let again = Maze::from_bytes(&copy.to_bytes()).unwrap();
+        assert_eq!(again.player, again.start);
+    }
+
+    #[test]
+    fn test_bytes_round_trip_with_diagonals() {
+        let maze = Maze::new_with_diagonals(5, 5, 8);
+        let copy = Maze::from_bytes(&maze.to_bytes()).unwrap();
+        assert_eq!(copy.connections, maze.connections);
+        assert_eq!(copy.solve(), maze.solve());
+    }
+
+    #[test]
+    fn test_toroidal_maze_wraps_between_edges() {
+        let crosses_edge = |a: Position, b: Position| a.x.abs_diff(b.x) > 1 || a.y.abs_diff(b.y) > 1;
+
+        let maze = Maze::new_toroidal(5, 5, 0);
+        assert_eq!(maze.move_pos(Position { x: 2, y: 0 }, Direction::North), Some(Position { x: 2, y: 4 }));
+        assert_eq!(maze.move_pos(Position { x: 4, y: 1 }, Direction::East), Some(Position { x: 0, y: 1 }));
+        assert_eq!(maze.visited.len(), 25);
+        assert!(maze.connections.iter().any(|&(a, b)| a.y == 0 && b.y == 4 && a.x == b.x));
+
+        // Start and end are neighbours across the corner, so some maze's solution wraps
+        let wrapped = (0..20)
+            .map(|seed| Maze::new_toroidal(5, 5, seed))
+            .find(|maze| maze.solve().unwrap().windows(2).any(|pair| crosses_edge(pair[0], pair[1])))
+            .expect("some toroidal maze should solve through a wrapped passage");
+        let path = wrapped.solve().unwrap();
+        assert!(path.windows(2).all(|pair| wrapped.connections.contains(&(pair[0], pair[1]))));
+        assert_eq!(wrapped.solve_astar().unwrap().len(), path.len());
+        assert_eq!(Maze::from_bytes(&wrapped.to_bytes()).unwrap().solve(), Some(path));
+    }
+
+    #[test]
+    fn test_solve_directions_lead_to_end() {
+        for mut maze in [Maze::new_seeded(7, 5, 3), Maze::new_with_diagonals(6, 6, 9), Maze::new_toroidal(5, 5, 1)] {
+            let directions = maze.solve_directions().unwrap();
+            assert_eq!(directions.len() + 1, maze.solve().unwrap().len());
+            for dir in directions {
+                assert!(maze.try_move(dir), "{dir:?} should follow an open passage");
+            }
+            assert!(maze.is_at_end());
+        }
+    }
+
+    #[test]
+    fn test_regenerate_matches_new_seeded() {
+        let mut maze = Maze::new_seeded(7, 5, 1);
+        let first = maze.solve_directions().unwrap();
+        maze.try_move(first[0]);
+        maze.regenerate(2);
+        let expected = Maze::new_seeded(7, 5, 2);
+        assert_eq!(maze.connections, expected.connections);
+        assert_eq!(maze.player, maze.start);
+        assert!(maze.move_history().is_empty());
+        assert_eq!(maze.visited.len(), 35);
+
+        let mut diagonal = Maze::new_with_diagonals(5, 5, 3);
+        diagonal.regenerate(4);
+        assert_eq!(diagonal.connections, Maze::new_with_diagonals(5, 5, 4).connections);
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_bad_data() {
+        let bytes = Maze::new_seeded(4, 4, 2).to_bytes();
+        assert!(Maze::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Maze::from_bytes(&[bytes.as_slice(), &[0]].concat()).is_err());
+
+        let mut bad_start = bytes.clone();
+        bad_start[16] = 4;
+        assert!(Maze::from_bytes(&bad_start).is_err());
+    }
+
     #[test]
     fn test_maze_has_all_cells_visited() {
         let maze = Maze::new(3, 3);
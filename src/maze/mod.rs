@@ -1,3 +1,25 @@
+//! # Puzzle Engine - Maze Module
+//!
+//! Two kinds of maze are provided:
+//! - `grid_maze` — a rectangular grid of cells carved into a perfect maze.
+//! - `network_maze` — an arbitrary graph of connected nodes.
+//!
+//! Both are re-exported under distinct names and share the [`Solvable`] trait.
+//!
+//! ## Example
+//! ```
+//! use puzzle_engine::maze::{GridMaze, NetworkMaze, Solvable};
+//! let grid = GridMaze::new(5, 5);
+//! let network = NetworkMaze::new(10).unwrap();
+//! assert!(grid.shortest_path_len().is_some());
+//! assert!(network.shortest_path_len().is_some());
+//! ```
+
 pub mod grid_maze;
 
 pub mod network_maze;
+pub mod traits;
+
+pub use grid_maze::Maze as GridMaze;
+pub use network_maze::Maze as NetworkMaze;
+pub use traits::Solvable;
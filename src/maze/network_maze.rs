@@ -1,6 +1,7 @@
 use rand::prelude::*;
 use rand::rng;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct NodeId(pub usize);
@@ -13,11 +14,35 @@ pub struct Maze {
     pub graph: HashMap<NodeId, Vec<NodeId>>,
 }
 
+/// Structural statistics about a network maze, as returned by [`Maze::stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MazeStats {
+    pub node_count: usize,
+    pub edge_count: usize,
+    /// Nodes with exactly one neighbor.
+    pub dead_ends: usize,
+    /// Whether any loop exists, i.e. some pair of nodes is joined by more than one route.
+    pub has_cycle: bool,
+}
+
 #[derive(Debug)]
 pub enum MazeError {
     TooFewNodes,
+    /// The node is not part of the maze.
+    UnknownNode(NodeId),
+}
+
+impl fmt::Display for MazeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MazeError::TooFewNodes => write!(f, "maze requires at least 2 nodes"),
+            MazeError::UnknownNode(node) => write!(f, "node {} is not in the maze", node.0),
+        }
+    }
 }
 
+impl std::error::Error for MazeError {}
+
 impl Maze {
     /// Creates a new randomly generated maze with a specified number of nodes.
     /// 
@@ -27,16 +52,44 @@ impl Maze {
     /// println!("Start: {:?}, End: {:?}", maze.start, maze.end);
     /// ```
     pub fn new(num_nodes: usize) -> Result<Self, MazeError> {
+        Self::generate(num_nodes, num_nodes / 3, &mut rng())
+    }
+
+    /// Creates a maze whose layout is fully determined by `seed`, with the number of extra
+    /// edges (beyond the spanning tree that keeps every node reachable) set to
+    /// `extra_edge_ratio * num_nodes`, rounded.
+    ///
+    /// A ratio of 0 gives a pure tree with exactly one route between any two nodes; larger
+    /// ratios add more loops, up to a complete graph. [`Maze::new`] uses a ratio of about 1/3.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MazeError::TooFewNodes`] if `num_nodes` is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use puzzle_engine::maze::network_maze::Maze;
+    /// let tree = Maze::new_with_density(10, 0.0, 1).unwrap();
+    /// assert_eq!(tree.stats().edge_count, 9);
+    /// assert!(!tree.stats().has_cycle);
+    /// ```
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss, clippy::cast_sign_loss)]
+    pub fn new_with_density(num_nodes: usize, extra_edge_ratio: f32, seed: u64) -> Result<Self, MazeError> {
+        let extra_edges = (num_nodes as f32 * extra_edge_ratio.max(0.0)).round() as usize;
+        Self::generate(num_nodes, extra_edges, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Internal function building a random spanning tree plus `extra_edges` distinct extra edges.
+    fn generate<R: Rng>(num_nodes: usize, extra_edges: usize, rng: &mut R) -> Result<Self, MazeError> {
         if num_nodes < 2 {
             return Err(MazeError::TooFewNodes);
         }
 
-        let mut rng = rng();
         let mut graph: HashMap<NodeId, Vec<NodeId>> = HashMap::new();
 
         // Ensure all nodes are connected: build a random spanning tree first
         let mut nodes: Vec<NodeId> = (0..num_nodes).map(NodeId).collect();
-        nodes.shuffle(&mut rng);
+        nodes.shuffle(rng);
         for i in 1..nodes.len() {
             let a = nodes[i];
             let b = nodes[rng.random_range(0..i)];
@@ -44,14 +97,16 @@ impl Maze {
             graph.entry(b).or_default().push(a);
         }
 
-        // Add a few random edges
-        let extra_edges = num_nodes / 3;
-        for _ in 0..extra_edges {
+        // Add random extra edges, stopping early if the graph becomes complete
+        let max_extra = num_nodes * (num_nodes - 1) / 2 - (num_nodes - 1);
+        let mut added = 0;
+        while added < extra_edges.min(max_extra) {
             let a = NodeId(rng.random_range(0..num_nodes));
             let b = NodeId(rng.random_range(0..num_nodes));
             if a != b && !graph.get(&a).map_or(false, |v| v.contains(&b)) {
                 graph.entry(a).or_default().push(b);
                 graph.entry(b).or_default().push(a);
+                added += 1;
             }
         }
 
@@ -74,6 +129,67 @@ impl Maze {
         self.graph.get(&node).map(|v| v.as_slice()).unwrap_or(&[])
     }
 
+    /// Adds a new, unconnected node to the maze and returns its id.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut maze = puzzle_engine::maze::network_maze::Maze::new(3).unwrap();
+    /// let node = maze.add_node();
+    /// assert!(maze.neighbors(node).is_empty());
+    /// ```
+    pub fn add_node(&mut self) -> NodeId {
+        let id = NodeId(self.graph.keys().map(|node| node.0 + 1).max().unwrap_or(0));
+        self.graph.insert(id, Vec::new());
+        id
+    }
+
+    /// Connects two nodes with an undirected edge.
+    /// Self-loops and edges that already exist are ignored.
+    ///
+    /// # Errors
+    /// Returns [`MazeError::UnknownNode`] if either node is not in the maze; use
+    /// [`Maze::add_node`] to create nodes.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut maze = puzzle_engine::maze::network_maze::Maze::new(3).unwrap();
+    /// let node = maze.add_node();
+    /// maze.add_edge(maze.start, node).unwrap();
+    /// assert!(maze.neighbors(node).contains(&maze.start));
+    /// ```
+    pub fn add_edge(&mut self, a: NodeId, b: NodeId) -> Result<(), MazeError> {
+        for node in [a, b] {
+            if !self.graph.contains_key(&node) {
+                return Err(MazeError::UnknownNode(node));
+            }
+        }
+        if a == b || self.neighbors(a).contains(&b) {
+            return Ok(());
+        }
+        self.graph.entry(a).or_default().push(b);
+        self.graph.entry(b).or_default().push(a);
+        Ok(())
+    }
+
+    /// Removes the undirected edge between two nodes, if present.
+    ///
+    /// # Examples
+    /// ```
+    /// let mut maze = puzzle_engine::maze::network_maze::Maze::new(3).unwrap();
+    /// let node = maze.add_node();
+    /// maze.add_edge(maze.start, node).unwrap();
+    /// maze.remove_edge(node, maze.start);
+    /// assert!(maze.neighbors(node).is_empty());
+    /// ```
+    pub fn remove_edge(&mut self, a: NodeId, b: NodeId) {
+        if let Some(neighbors) = self.graph.get_mut(&a) {
+            neighbors.retain(|&n| n != b);
+        }
+        if let Some(neighbors) = self.graph.get_mut(&b) {
+            neighbors.retain(|&n| n != a);
+        }
+    }
+
     /// Finds a path from the start node to the end node using BFS.
     /// Returns `Some(Vec<NodeId>)` if a path exists, or `None` otherwise.
     ///
@@ -177,6 +293,47 @@ impl Maze {
     pub fn is_at_end(&self) -> bool {
         self.current == self.end
     }
+
+    /// Summarizes the shape of the maze: its size, how many dead ends it has and whether
+    /// it contains any cycles.
+    ///
+    /// # Examples
+    /// ```
+    /// let maze = puzzle_engine::maze::network_maze::Maze::new(10).unwrap();
+    /// let stats = maze.stats();
+    /// assert_eq!(stats.node_count, 10);
+    /// assert!(stats.edge_count >= 9);
+    /// ```
+    pub fn stats(&self) -> MazeStats {
+        let node_count = self.graph.len();
+        let edge_count = self.graph.values().map(Vec::len).sum::<usize>() / 2;
+        let dead_ends = self.graph.values().filter(|neighbors| neighbors.len() == 1).count();
+
+        // A forest has exactly one fewer edge than nodes per connected component
+        let mut visited = HashSet::new();
+        let mut components = 0;
+        for &node in self.graph.keys() {
+            if !visited.insert(node) {
+                continue;
+            }
+            components += 1;
+            let mut queue = VecDeque::from([node]);
+            while let Some(current) = queue.pop_front() {
+                for &next in self.neighbors(current) {
+                    if visited.insert(next) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        MazeStats {
+            node_count,
+            edge_count,
+            dead_ends,
+            has_cycle: edge_count + components > node_count,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -193,6 +350,23 @@ mod tests {
         assert_eq!(path.last().cloned(), Some(maze.end));
     }
 
+    #[test]
+    fn test_density_controls_extra_edges() {
+        let tree = Maze::new_with_density(30, 0.0, 4).unwrap();
+        assert_eq!(tree.stats().edge_count, 29);
+        assert!(!tree.stats().has_cycle);
+
+        let sparse = Maze::new_with_density(30, 0.2, 4).unwrap().stats().edge_count;
+        let dense = Maze::new_with_density(30, 1.0, 4).unwrap().stats().edge_count;
+        assert_eq!(sparse, 29 + 6);
+        assert_eq!(dense, 29 + 30);
+
+        // Requests beyond a complete graph are capped
+        let complete = Maze::new_with_density(5, 100.0, 4).unwrap();
+        assert_eq!(complete.stats().edge_count, 10);
+        assert!(Maze::new_with_density(1, 1.0, 4).is_err());
+    }
+
     #[test]
     fn test_maze_tarversal_small_maze_finds_end() {
         let mut maze = Maze::new(10).unwrap();
@@ -231,4 +405,77 @@ mod tests {
         let invalid = NodeId(9999);
         assert!(maze.traverse(invalid).is_err());
     }
+
+    #[test]
+    fn test_hand_built_graph_pathfinding() {
+        let mut maze = Maze::new(2).unwrap();
+        maze.remove_edge(maze.start, maze.end);
+        assert!(maze.neighbors(maze.start).is_empty());
+        assert_eq!(maze.find_path(), None);
+
+        let a = maze.add_node();
+        let b = maze.add_node();
+        assert_eq!((a, b), (NodeId(2), NodeId(3)));
+        maze.add_edge(maze.start, a).unwrap();
+        maze.add_edge(a, b).unwrap();
+        maze.add_edge(b, maze.end).unwrap();
+
+        assert_eq!(maze.find_path(), Some(vec![maze.start, a, b, maze.end]));
+    }
+
+    #[test]
+    fn test_add_edge_keeps_adjacency_symmetric() {
+        let mut maze = Maze::new(2).unwrap();
+        let a = maze.add_node();
+        maze.add_edge(a, maze.end).unwrap();
+        maze.add_edge(maze.end, a).unwrap();
+        maze.add_edge(a, a).unwrap();
+        assert_eq!(maze.neighbors(a), &[maze.end]);
+        assert_eq!(maze.neighbors(maze.end).iter().filter(|&&n| n == a).count(), 1);
+
+        maze.remove_edge(maze.end, a);
+        assert!(maze.neighbors(a).is_empty());
+        assert!(!maze.neighbors(maze.end).contains(&a));
+    }
+
+    #[test]
+    fn test_add_edge_rejects_unknown_nodes() {
+        let mut maze = Maze::new(2).unwrap();
+        let missing = NodeId(7);
+        assert!(matches!(maze.add_edge(maze.start, missing), Err(MazeError::UnknownNode(node)) if node == missing));
+        assert!(matches!(maze.add_edge(missing, maze.end), Err(MazeError::UnknownNode(node)) if node == missing));
+        assert!(!maze.graph.contains_key(&missing));
+        assert!(!maze.neighbors(maze.start).contains(&missing));
+    }
+
+    #[test]
+    fn test_stats_tree_and_cycle() {
+        let mut maze = Maze::new(2).unwrap();
+        let a = maze.add_node();
+        let b = maze.add_node();
+        let c = maze.add_node();
+        maze.add_edge(maze.start, a).unwrap();
+        maze.add_edge(a, b).unwrap();
+        maze.add_edge(a, c).unwrap();
+
+        let stats = maze.stats();
+        assert_eq!(
+            stats,
+            MazeStats { node_count: 5, edge_count: 4, dead_ends: 3, has_cycle: false }
+        );
+
+        maze.add_edge(b, c).unwrap();
+        let stats = maze.stats();
+        assert!(stats.has_cycle);
+        assert_eq!((stats.edge_count, stats.dead_ends), (5, 1));
+    }
+
+    #[test]
+    fn test_maze_error_display_and_boxing() {
+        let err = Maze::new(1).unwrap_err();
+        assert_eq!(err.to_string(), "maze requires at least 2 nodes");
+
+        let boxed: Box<dyn std::error::Error> = Box::new(err);
+        assert_eq!(boxed.to_string(), "maze requires at least 2 nodes");
+    }
 }
@@ -0,0 +1,56 @@
+use super::{grid_maze, network_maze};
+
+/// Trait for operations shared by every kind of maze
+pub trait Solvable {
+    /// Check whether the player has reached the end of the maze
+    fn is_solved(&self) -> bool;
+
+    /// Number of steps on the shortest route from the start to the end, if one exists
+    fn shortest_path_len(&self) -> Option<usize>;
+}
+
+impl Solvable for grid_maze::Maze {
+    fn is_solved(&self) -> bool {
+        self.is_at_end()
+    }
+
+    fn shortest_path_len(&self) -> Option<usize> {
+        grid_maze::Maze::shortest_path_len(self)
+    }
+}
+
+impl Solvable for network_maze::Maze {
+    fn is_solved(&self) -> bool {
+        self.current == self.end
+    }
+
+    fn shortest_path_len(&self) -> Option<usize> {
+        self.find_path().map(|path| path.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn describe(maze: &impl Solvable) -> (bool, Option<usize>) {
+        (maze.is_solved(), maze.shortest_path_len())
+    }
+
+    #[test]
+    fn test_generic_over_grid_maze() {
+        let mut maze = grid_maze::Maze::new_seeded(5, 5, 7);
+        let steps = maze.solve().unwrap().len() - 1;
+        assert_eq!(describe(&maze), (false, Some(steps)));
+        maze.player = *maze.solve().unwrap().last().unwrap();
+        assert!(describe(&maze).0);
+    }
+
+    #[test]
+    fn test_generic_over_network_maze() {
+        let mut maze = network_maze::Maze::new(2).unwrap();
+        assert_eq!(describe(&maze), (false, Some(1)));
+        maze.current = maze.end;
+        assert!(describe(&maze).0);
+    }
+}
@@ -0,0 +1,33 @@
+//! The most commonly used types from every puzzle module.
+//!
+//! ```
+//! use puzzle_engine::prelude::*;
+//!
+//! let caesar = Caesar::new(3);
+//! assert_eq!(caesar.encrypt("abc"), "def");
+//! ```
+#![cfg_attr(
+    feature = "std",
+    doc = r"
+Chess names are used unqualified; the Go board is left out to avoid clashing with the
+chess [`Board`], and is reachable through [`Game::board`].
+
+```
+use puzzle_engine::prelude::*;
+
+let mut board = Board::new();
+board.try_move(Position::new('e', 2).unwrap(), Position::new('e', 4).unwrap(), None).unwrap();
+
+let mut game = Game::new(9);
+game.play(Point::new(4, 4)).unwrap();
+```
+"
+)]
+
+#[cfg(feature = "std")]
+pub use crate::chess::{Board, Color, GameState, Move, Piece, PieceType, Position};
+pub use crate::cipher::prelude::*;
+#[cfg(feature = "std")]
+pub use crate::go::{Game, GameResult, Point, Stone};
+#[cfg(feature = "std")]
+pub use crate::maze::{GridMaze, NetworkMaze, Solvable};